@@ -0,0 +1,64 @@
+use ics::parameters::TzIDParam;
+use ics::properties::{BusyType, DtEnd, DtStart, Frequency, RRuleBuilder, Summary, Weekday};
+use ics::{Availability, Available, ICalendar};
+
+fn main() -> std::io::Result<()> {
+    // Create the availability which marks all time as unavailable except for
+    // the time spans that are added to it.
+    let mut availability =
+        Availability::new("0428C7D2-688E-4D2E-AC52-CD112E2469DF", "20111005T133225Z");
+    availability.push(BusyType::busy_unavailable());
+    availability.push(Summary::new("Office hours"));
+
+    // The time span is available on weekdays from 9 to 5. DTSTART is the first
+    // occurrence and the recurrence rule repeats it every weekday.
+    let mut dtstart = DtStart::new("20111003T090000");
+    dtstart.add(TzIDParam::new("America/Montreal"));
+    let mut available = Available::new(
+        "34EDA59B-6BB1-4E94-A66C-64999089C0AF",
+        "20111005T133225Z",
+        dtstart,
+    );
+    let mut dtend = DtEnd::new("20111003T170000");
+    dtend.add(TzIDParam::new("America/Montreal"));
+    available.push(dtend);
+    let rrule = RRuleBuilder::new()
+        .freq(Frequency::Weekly)
+        .by_day(&[
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ])
+        .build()
+        .expect("frequency is set");
+    available.push(rrule);
+    availability.add_available(available);
+
+    let mut calendar = ICalendar::new("2.0", "-//example//NONSGML Availability//EN");
+    calendar.add_availability(availability);
+    // Write calendar to file
+    calendar.save_file("availability.ics")?;
+    Ok(())
+
+    /* inside availability.ics
+    BEGIN:VCALENDAR
+    VERSION:2.0
+    PRODID:-//example//NONSGML Availability//EN
+    BEGIN:VAVAILABILITY
+    UID:0428C7D2-688E-4D2E-AC52-CD112E2469DF
+    DTSTAMP:20111005T133225Z
+    BUSYTYPE:BUSY-UNAVAILABLE
+    SUMMARY:Office hours
+    BEGIN:AVAILABLE
+    UID:34EDA59B-6BB1-4E94-A66C-64999089C0AF
+    DTSTAMP:20111005T133225Z
+    DTSTART;TZID=America/Montreal:20111003T090000
+    DTEND;TZID=America/Montreal:20111003T170000
+    RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR
+    END:AVAILABLE
+    END:VAVAILABILITY
+    END:VCALENDAR
+    */
+}
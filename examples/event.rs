@@ -1,5 +1,5 @@
 use ics::properties::{Categories, Description, DtEnd, DtStart, Organizer, Status, Summary};
-use ics::{escape_text, Event, ICalendar};
+use ics::{escape_text, Event, ICalendarBuilder};
 
 fn main() -> std::io::Result<()> {
     // Create event which contains the information regarding the conference
@@ -23,8 +23,11 @@ fn main() -> std::io::Result<()> {
 
     // Create new iCalendar object
     // An iCalendar object must at least consist a component and the VERSION and
-    // PRODID property.
-    let mut calendar = ICalendar::new("2.0", "-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN");
+    // PRODID property. The builder sets VERSION to 2.0.
+    let mut calendar = ICalendarBuilder::new("-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN")
+        .calscale("GREGORIAN")
+        .name("Conferences")
+        .build();
     calendar.add_event(event);
     // Write calendar to file
     calendar.save_file("event.ics")?;
@@ -34,6 +37,8 @@ fn main() -> std::io::Result<()> {
     BEGIN:VCALENDAR
     VERSION:2.0
     PRODID:-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN
+    CALSCALE:GREGORIAN
+    X-WR-CALNAME:Conferences
     BEGIN:VEVENT
     UID:b68378cf-872d-44f1-9703-5e3725c56e71
     DTSTAMP:19960704T120000Z
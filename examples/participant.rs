@@ -0,0 +1,57 @@
+use ics::components::Property;
+use ics::properties::{CalendarAddress, DtStart, LocationType, ParticipantType, Summary, URL};
+use ics::{Event, ICalendar, Participant, StructuredLocation};
+
+fn main() -> std::io::Result<()> {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "20221005T133225Z");
+    event.push(DtStart::new("20221012T160000Z"));
+    event.push(Summary::new("Quarterly review"));
+
+    // A participant describes someone who takes part in the event in more
+    // detail than an attendee, e.g. the role they have.
+    let mut speaker = Participant::new(
+        "f1a3b6a0-1b9e-4c1d-9f47-8d2c5f0e4a11",
+        ParticipantType::speaker(),
+    );
+    speaker.push(CalendarAddress::new("mailto:jane@example.com"));
+
+    // The speaker joins from a different place than the other participants.
+    // RFC9073 properties without a type in this crate can be created with the
+    // generic property type.
+    let mut location = StructuredLocation::new("3c7e2d94-5f6a-4b8e-a1d0-9e6f2b4c8a57");
+    location.push(Property::new("NAME", "Studio B"));
+    location.push(LocationType::new("office"));
+    location.push(URL::new("https://example.com/studio-b"));
+    speaker.add_location(location);
+    event.add_participant(speaker);
+
+    let mut calendar = ICalendar::new("2.0", "-//example//NONSGML Participants//EN");
+    calendar.add_event(event);
+    // Write calendar to file
+    calendar.save_file("participant.ics")?;
+    Ok(())
+
+    /* inside participant.ics
+    BEGIN:VCALENDAR
+    VERSION:2.0
+    PRODID:-//example//NONSGML Participants//EN
+    BEGIN:VEVENT
+    UID:b68378cf-872d-44f1-9703-5e3725c56e71
+    DTSTAMP:20221005T133225Z
+    DTSTART:20221012T160000Z
+    SUMMARY:Quarterly review
+    BEGIN:VPARTICIPANT
+    UID:f1a3b6a0-1b9e-4c1d-9f47-8d2c5f0e4a11
+    PARTICIPANT-TYPE:SPEAKER
+    CALENDAR-ADDRESS:mailto:jane@example.com
+    BEGIN:VLOCATION
+    UID:3c7e2d94-5f6a-4b8e-a1d0-9e6f2b4c8a57
+    NAME:Studio B
+    LOCATION-TYPE:office
+    URL:https://example.com/studio-b
+    END:VLOCATION
+    END:VPARTICIPANT
+    END:VEVENT
+    END:VCALENDAR
+    */
+}
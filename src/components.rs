@@ -19,15 +19,19 @@
 //! }
 //! ```
 use crate::contentline;
-use std::borrow::Cow;
-use std::collections::BTreeMap;
-use std::fmt;
+use crate::parser::{self, ParseError};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
 
 /// A `Component` contains properties and sometimes sub-components.
 ///
 /// This can be used to create a new calendar component by either creating a
 /// wrapper type or just use it as it is.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Component<'a> {
     pub(crate) name: Cow<'a, str>,
     pub(crate) properties: Vec<Property<'a>>,
@@ -83,8 +87,10 @@ impl<'a> fmt::Display for Component<'a> {
 ///
 /// They are part of a component and define it. This can be used to create a
 /// new calendar property by either creating a wrapper type or just use it as
-/// it is.
+/// it is. The names of the property and its parameters are written in
+/// uppercase, the values are written as they are.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Property<'a> {
     pub(crate) key: Cow<'a, str>,
     pub(crate) value: Cow<'a, str>,
@@ -105,6 +111,66 @@ impl<'a> Property<'a> {
         }
     }
 
+    /// Creates a new non-standard property. The `X-` prefix is added to the
+    /// name if it is missing. The name may only contain letters, digits and
+    /// hyphens.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    ///
+    /// let property = Property::experimental("APPLE-TRAVEL-ADVISORY-BEHAVIOR", "AUTOMATIC");
+    /// assert_eq!(property.key(), "X-APPLE-TRAVEL-ADVISORY-BEHAVIOR");
+    /// ```
+    pub fn experimental<K, V>(name: K, value: V) -> Self
+    where
+        K: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        let name = name.into();
+        debug_assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'),
+            "name may only contain letters, digits and hyphens"
+        );
+        let is_prefixed = name
+            .get(..2)
+            .map_or(false, |prefix| prefix.eq_ignore_ascii_case("X-"));
+        let key = if is_prefixed {
+            name
+        } else {
+            Cow::Owned(format!("X-{}", name))
+        };
+        Property::new(key, value)
+    }
+
+    /// Returns the key of the property.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the property as it is written. Text values are
+    /// still escaped.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns an iterator over the keys and values of the parameters sorted
+    /// by key. The values are returned as they are written, so parsed values
+    /// keep their quotes.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    ///
+    /// let property = Property::parse_line("DTSTART;VALUE=DATE:20240101").unwrap();
+    /// assert_eq!(property.parameters().collect::<Vec<_>>(), [("VALUE", "DATE")]);
+    /// ```
+    pub fn parameters(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.parameters
+            .iter()
+            .map(|(key, value)| (key.as_ref(), value.as_ref()))
+    }
+
     /// Adds a parameter to a property.
     pub fn add<P>(&mut self, parameter: P)
     where
@@ -127,28 +193,55 @@ impl<'a> Property<'a> {
         self.parameters
             .iter()
             .fold(self.value.len() + self.key.len() + 1, |len, (k, v)| {
-                len + k.len() + v.len() + 2
+                len + k.len() + parameter_value_len(k, v) + 2
             })
     }
 
+    fn has_line_break(&self) -> bool {
+        let is_line_break = |c| c == '\r' || c == '\n';
+        self.value.contains(is_line_break)
+            || self.parameters.values().any(|v| v.contains(is_line_break))
+    }
+
     fn format<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
-        write!(writer, "{}", self.key)?;
+        write_name(writer, &self.key)?;
         for (key, value) in &self.parameters {
-            write!(writer, ";{}={}", key, value)?;
+            writer.write_char(';')?;
+            write_name(writer, key)?;
+            writer.write_char('=')?;
+            write_parameter_value(writer, key, value)?;
         }
         write!(writer, ":{}", self.value)
     }
 }
 
+impl Property<'static> {
+    /// Parses a single content line like `DTSTART;VALUE=DATE:20240101`. A
+    /// folded line is unfolded and the value is kept as it is, so text values
+    /// still need to be unescaped. Colons and semicolons in quoted parameter
+    /// values do not separate anything.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    ///
+    /// let property = Property::parse_line("DTSTART;VALUE=DATE:20240101").unwrap();
+    /// assert_eq!(property.key(), "DTSTART");
+    /// assert_eq!(property.value(), "20240101");
+    /// ```
+    pub fn parse_line(line: &str) -> Result<Self, ParseError> {
+        parser::read_property(line)
+    }
+}
+
 impl<'a> fmt::Display for Property<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let len = self.content_len();
-        if len <= contentline::LIMIT {
+        // Line breaks are normalized while folding.
+        if len <= contentline::LIMIT && !self.has_line_break() {
             self.format(f)?;
         } else {
-            let mut content = String::with_capacity(contentline::size(len));
-            self.format(&mut content)?;
-            contentline::fold(f, &content)?;
+            contentline::fold_property(f, &self.key, &self.parameters, &self.value)?;
         }
         writeln!(f, "\r")
     }
@@ -159,6 +252,14 @@ impl<'a> fmt::Display for Property<'a> {
 ///
 /// This can be used to create a new calendar parameter by either creating a
 /// wrapper type or just use it as it is.
+///
+/// Values that contain a colon, semicolon, comma or control character are
+/// written in double quotes. The values of the parameters that take a list,
+/// like `MEMBER`, are quoted one by one. Values that are already quoted are
+/// written as they are. Otherwise a value must not contain a double quote.
+/// This is checked in debug builds, the `validate()` methods report it as
+/// [`ValidationError::DoubleQuote`](crate::ValidationError::DoubleQuote) and
+/// the double quotes are dropped when the value is written.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Parameter<'a> {
     pub(crate) key: Cow<'a, str>,
@@ -172,17 +273,116 @@ impl<'a> Parameter<'a> {
         K: Into<Cow<'a, str>>,
         V: Into<Cow<'a, str>>,
     {
-        Parameter {
-            key: key.into(),
-            value: value.into(),
-        }
+        let key = key.into();
+        let value = value.into();
+        debug_assert!(
+            is_valid_parameter_value(&key, &value),
+            "parameter value must not contain a double quote"
+        );
+        Parameter { key, value }
     }
 }
 
 impl<'a> fmt::Display for Parameter<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}={}", self.key, self.value)
+        write_name(f, &self.key)?;
+        f.write_char('=')?;
+        write_parameter_value(f, &self.key, &self.value)
+    }
+}
+
+// Parameters that can have a list of values separated by commas.
+const LIST_PARAMETERS: [&str; 4] = ["DELEGATED-FROM", "DELEGATED-TO", "FEATURE", "MEMBER"];
+
+// Splits a parameter value into the values that are quoted on their own.
+// Commas in quoted values do not separate anything.
+fn parameter_values<'b>(key: &str, value: &'b str) -> impl Iterator<Item = &'b str> {
+    let is_list = LIST_PARAMETERS
+        .iter()
+        .any(|name| key.eq_ignore_ascii_case(name));
+    let mut in_quotes = false;
+    value.split(move |c| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        is_list && !in_quotes && c == ','
+    })
+}
+
+// Returns the length of a parameter value as it is written, with the quotes
+// that are added and without the ones that are dropped.
+fn parameter_value_len(key: &str, value: &str) -> usize {
+    parameter_values(key, value)
+        .map(|value| {
+            // + 1 for the comma after each value
+            if is_quoted(value) {
+                value.len() + 1
+            } else {
+                let quotes = if needs_quotes(value) { 2 } else { 0 };
+                value.len() - value.matches('"').count() + quotes + 1
+            }
+        })
+        .sum::<usize>()
+        - 1
+}
+
+// Checks if a parameter value has to be put in double quotes.
+fn needs_quotes(value: &str) -> bool {
+    !is_quoted(value)
+        && value.contains(|c: char| c == ':' || c == ';' || c == ',' || c.is_control())
+}
+
+// Checks for exactly one pair of quotes around the value.
+fn is_quoted(value: &str) -> bool {
+    value.len() >= 2
+        && value.starts_with('"')
+        && value.ends_with('"')
+        && !value[1..value.len() - 1].contains('"')
+}
+
+// Checks that a parameter value contains no double quotes except for the
+// quotes around a value.
+pub(crate) fn is_valid_parameter_value(key: &str, value: &str) -> bool {
+    parameter_values(key, value).all(|value| is_quoted(value) || !value.contains('"'))
+}
+
+// Writes a property or parameter name in uppercase. Names are
+// case-insensitive but some consumers only accept uppercase names.
+pub(crate) fn write_name<W: fmt::Write>(writer: &mut W, name: &str) -> fmt::Result {
+    if !name.bytes().any(|b| b.is_ascii_lowercase()) {
+        return writer.write_str(name);
+    }
+    name.chars()
+        .try_for_each(|c| writer.write_char(c.to_ascii_uppercase()))
+}
+
+pub(crate) fn write_parameter_value<W: fmt::Write>(
+    writer: &mut W,
+    key: &str,
+    value: &str,
+) -> fmt::Result {
+    for (i, value) in parameter_values(key, value).enumerate() {
+        if i > 0 {
+            writer.write_char(',')?;
+        }
+        if is_quoted(value) {
+            writer.write_str(value)?;
+            continue;
+        }
+        // Double quotes cannot be written inside of a value, so they are
+        // dropped. Validation reports them.
+        let quotes = needs_quotes(value);
+        if quotes {
+            writer.write_char('"')?;
+        }
+        for part in value.split('"') {
+            writer.write_str(part)?;
+        }
+        if quotes {
+            writer.write_char('"')?;
+        }
     }
+    Ok(())
 }
 
 /// `Parameters` is a collection of `Parameter`s. It can be created with the
@@ -207,4 +407,89 @@ mod tests {
         let expected = 25;
         assert_eq!(property.content_len(), expected);
     }
+
+    #[test]
+    fn quoted_parameter() {
+        let mut property = Property::new("ATTENDEE", "mailto:jdoe@example.com");
+        property.add(Parameter::new("CN", "Doe, John"));
+        assert_eq!(property.content_len(), 47);
+        assert_eq!(
+            property.to_string(),
+            "ATTENDEE;CN=\"Doe, John\":mailto:jdoe@example.com\r\n"
+        );
+        let parameter = Parameter::new("ALTREP", "cid:part1.0001@example.org");
+        assert_eq!(
+            parameter.to_string(),
+            "ALTREP=\"cid:part1.0001@example.org\""
+        );
+        let parameter = Parameter::new("MEMBER", "\"mailto:a@example.com\"");
+        assert_eq!(parameter.to_string(), "MEMBER=\"mailto:a@example.com\"");
+        assert_eq!(Parameter::new("CN", "John").to_string(), "CN=John");
+        let parameter = Parameter::new("MEMBER", "mailto:a@example.com,mailto:b@example.com");
+        assert_eq!(
+            parameter.to_string(),
+            "MEMBER=\"mailto:a@example.com\",\"mailto:b@example.com\""
+        );
+        let parameter = Parameter::new("FEATURE", "AUDIO,VIDEO");
+        assert_eq!(parameter.to_string(), "FEATURE=AUDIO,VIDEO");
+    }
+
+    #[test]
+    fn parameter_with_quote() {
+        // The constructor rejects double quotes in debug builds.
+        let parameter = |key: &'static str, value: &'static str| Parameter {
+            key: key.into(),
+            value: value.into(),
+        };
+        let mut property = Property::new("ATTENDEE", "mailto:jdoe@example.com");
+        property.add(parameter("CN", "Doe \"J\", John"));
+        assert_eq!(property.content_len(), 49);
+        assert_eq!(
+            property.to_string(),
+            "ATTENDEE;CN=\"Doe J, John\":mailto:jdoe@example.com\r\n"
+        );
+        assert_eq!(parameter("CN", "\"a\"b\"").to_string(), "CN=ab");
+        let mut property = Property::new("ATTENDEE", "mailto:jdoe@example.com");
+        property.add(parameter("MEMBER", "\"a:b\",\"c\"d\""));
+        assert_eq!(property.content_len(), 48);
+        assert_eq!(
+            property.to_string(),
+            "ATTENDEE;MEMBER=\"a:b\",cd:mailto:jdoe@example.com\r\n"
+        );
+        assert!(!super::is_valid_parameter_value("CN", "\"a\"b\""));
+        assert!(!super::is_valid_parameter_value(
+            "CN",
+            "John \"Johnny\" Doe"
+        ));
+        assert!(super::is_valid_parameter_value("CN", "\"Doe, John\""));
+        let members = "\"mailto:a,b@example.com\",\"mailto:c@example.com\"";
+        assert!(super::is_valid_parameter_value("MEMBER", members));
+        assert_eq!(
+            Parameter::new("MEMBER", members).to_string(),
+            format!("MEMBER={}", members)
+        );
+        assert!(!super::is_valid_parameter_value("MEMBER", "\"a\"b\",\"c\""));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "parameter value must not contain a double quote")]
+    fn parameter_with_quote_invalid() {
+        Parameter::new("CN", "Doe \"J\", John");
+    }
+
+    #[test]
+    fn experimental() {
+        let property = Property::experimental("WR-CALNAME", "Holidays");
+        assert_eq!(property, Property::new("X-WR-CALNAME", "Holidays"));
+        let property = Property::experimental("x-wr-calname", "Holidays");
+        assert_eq!(property.key(), "x-wr-calname");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn experimental_invalid_name() {
+        Property::experimental("WR CALNAME", "Holidays");
+    }
 }
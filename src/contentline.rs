@@ -1,47 +1,539 @@
 //! Algorithms for content lines.
-use std::fmt;
+//!
+//! Content lines must be folded after around 75 bytes by inserting a carriage
+//! return and line feed followed by whitespace. This crate uses a space
+//! character as white space but it could also be a horizontal tab. Unfolding
+//! is the reverse process and removes every line break that is immediately
+//! followed by a single whitespace character (see [RFC5545 3.1. Content Lines](https://tools.ietf.org/html/rfc5545#section-3.1)).
+use crate::components::{self, Parameters};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::ops::Range;
 
-// Content lines must be folded after around 75 bytes by inserting a carriage
-// return and line feed followed by whitespace. This crate uses a space
-// character as white space but it could also be a horizontal tab.
+/// The maximum length of a content line in bytes excluding the line break.
 pub const LIMIT: usize = 75;
-const LINE_BREAK: &str = "\r\n ";
 
-pub fn fold<W: fmt::Write>(writer: &mut W, mut content: &str) -> fmt::Result {
-    let mut boundary = next_boundary(content, LIMIT);
+/// The whitespace character that is inserted after the line break of a fold.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum FoldWhitespace {
+    /// Space character (Default Value)
+    Space,
+    /// Horizontal tab character
+    Tab,
+}
+
+impl FoldWhitespace {
+    fn line_break(&self) -> &'static str {
+        match self {
+            FoldWhitespace::Space => "\r\n ",
+            FoldWhitespace::Tab => "\r\n\t",
+        }
+    }
+}
+
+impl Default for FoldWhitespace {
+    fn default() -> Self {
+        FoldWhitespace::Space
+    }
+}
+
+/// Writes the content folded into lines of at most [`LIMIT`] bytes. Lines are
+/// never split in the middle of a UTF-8 character.
+///
+/// A content line cannot contain line breaks, so content that bypassed
+/// [`escape_text`](crate::escape_text) is normalized: Existing folds (CRLF
+/// followed by a space or horizontal tab) are removed before folding again and
+/// any other CR, LF or CRLF is written as the escaped `\n`.
+///
+/// # Example
+/// ```
+/// use ics::contentline::fold;
+///
+/// let mut line = String::new();
+/// fold(&mut line, "DESCRIPTION:Line one\r\nLine two").unwrap();
+/// assert_eq!(line, "DESCRIPTION:Line one\\nLine two");
+/// ```
+pub fn fold<W: fmt::Write>(writer: &mut W, content: &str) -> fmt::Result {
+    fold_with(writer, content, FoldWhitespace::Space)
+}
+
+/// Writes the content folded like [`fold`] but with the given whitespace
+/// character after each line break.
+pub fn fold_with<W: fmt::Write>(
+    writer: &mut W,
+    content: &str,
+    whitespace: FoldWhitespace,
+) -> fmt::Result {
+    fold_lines(writer, content, whitespace.line_break(), LIMIT, 0)
+}
+
+/// Writes the content folded like [`fold`] but into lines of at most `limit`
+/// bytes instead of [`LIMIT`]. Some consumers require shorter lines. Limits
+/// below 3 bytes are raised to 3 because each continuation line starts with
+/// the whitespace. A character that is longer than the limit is not split and
+/// gets a line of its own.
+pub fn fold_with_width<W: fmt::Write>(writer: &mut W, content: &str, limit: usize) -> fmt::Result {
+    let line_break = FoldWhitespace::Space.line_break();
+    fold_lines(writer, content, line_break, limit.max(MIN_LIMIT), 0)
+}
+
+// The smallest line length that still fits content after the whitespace
+const MIN_LIMIT: usize = 3;
+
+/// Writes the content folded like [`fold`] but folds before a space if there
+/// is one within the last `window` bytes of a line. Words are only split if
+/// they are longer than the window.
+///
+/// The space stays at the beginning of the next line, so unfolding restores
+/// the content exactly and consumers that display folded lines do not show
+/// split words.
+pub fn fold_at_whitespace<W: fmt::Write>(
+    writer: &mut W,
+    content: &str,
+    window: usize,
+) -> fmt::Result {
+    fold_lines(
+        writer,
+        content,
+        FoldWhitespace::Space.line_break(),
+        LIMIT,
+        window,
+    )
+}
+
+fn fold_lines<W: fmt::Write>(
+    writer: &mut W,
+    mut content: &str,
+    line_break: &str,
+    limit: usize,
+    window: usize,
+) -> fmt::Result {
+    if content.bytes().any(|b| b == b'\r' || b == b'\n') {
+        let content = normalize_line_breaks(content);
+        return fold_lines(writer, &content, line_break, limit, window);
+    }
+
+    let mut boundary = whitespace_boundary(content, limit, window);
     writer.write_str(&content[..boundary])?;
 
     while boundary < content.len() {
         content = &content[boundary..];
-        writer.write_str(LINE_BREAK)?;
-        let next_boundary = next_boundary(content, LIMIT - 1);
+        writer.write_str(line_break)?;
+        let next_boundary = whitespace_boundary(content, limit - 1, window);
         writer.write_str(&content[..next_boundary])?;
         boundary = next_boundary;
     }
     Ok(())
 }
 
-// TODO: unfold algorithm
+// Removes folds and escapes the remaining line breaks.
+fn normalize_line_breaks(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' if chars.peek() == Some(&'\n') => {
+                chars.next();
+                match chars.peek() {
+                    Some(' ') | Some('\t') => {
+                        chars.next();
+                    }
+                    _ => output.push_str("\\n"),
+                }
+            }
+            '\r' | '\n' => output.push_str("\\n"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+// Moves the boundary back to a space within the window. The space must not be
+// the first byte, otherwise no progress would be made.
+fn whitespace_boundary(input: &str, limit: usize, window: usize) -> usize {
+    let boundary = next_boundary(input, limit);
+    if boundary == input.len() || window == 0 {
+        return boundary;
+    }
+    let start = boundary.saturating_sub(window).max(1);
+    match input.as_bytes()[start..boundary]
+        .iter()
+        .rposition(|&b| b == b' ')
+    {
+        Some(index) => start + index,
+        None => boundary,
+    }
+}
+
+/// Writes a property folded like [`fold`] from its name, parameters and value
+/// as `NAME;KEY=VALUE:VALUE`. Unlike [`fold`] the parts do not have to be
+/// joined into one string first, so nothing is allocated. Parameter values are
+/// quoted like the ones of a [`Parameter`](crate::components::Parameter) and
+/// the names are written in uppercase.
+///
+/// # Example
+/// ```
+/// use ics::contentline::fold_property;
+///
+/// let mut line = String::new();
+/// fold_property(&mut line, "SUMMARY", &ics::parameters!("LANGUAGE" => "en"), "Meeting").unwrap();
+/// assert_eq!(line, "SUMMARY;LANGUAGE=en:Meeting");
+/// ```
+pub fn fold_property<W: fmt::Write>(
+    writer: &mut W,
+    name: &str,
+    params: &Parameters,
+    value: &str,
+) -> fmt::Result {
+    let mut folder = Folder {
+        writer,
+        len: 0,
+        line_break: LineBreak::None,
+    };
+    components::write_name(&mut folder, name)?;
+    for (key, value) in params {
+        folder.write_char(';')?;
+        components::write_name(&mut folder, key)?;
+        folder.write_char('=')?;
+        components::write_parameter_value(&mut folder, key, value)?;
+    }
+    folder.write_char(':')?;
+    folder.write_str(value)?;
+    folder.finish()
+}
+
+// Line break characters that have been written but not handled yet because
+// the next character decides if they are a fold.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LineBreak {
+    None,
+    CarriageReturn,
+    CarriageReturnLineFeed,
+}
+
+// Folds the content while it is written in pieces. The output is the same as
+// the one of `fold` for the whole content.
+struct Folder<'w, W> {
+    writer: &'w mut W,
+    // Length of the current line
+    len: usize,
+    line_break: LineBreak,
+}
+
+impl<W: fmt::Write> Folder<'_, W> {
+    fn push(&mut self, c: char) -> fmt::Result {
+        match (self.line_break, c) {
+            (LineBreak::CarriageReturn, '\n') => {
+                self.line_break = LineBreak::CarriageReturnLineFeed;
+                Ok(())
+            }
+            // An existing fold is removed.
+            (LineBreak::CarriageReturnLineFeed, ' ')
+            | (LineBreak::CarriageReturnLineFeed, '\t') => {
+                self.line_break = LineBreak::None;
+                Ok(())
+            }
+            (_, '\r') => {
+                self.flush_line_break()?;
+                self.line_break = LineBreak::CarriageReturn;
+                Ok(())
+            }
+            (_, '\n') => {
+                self.flush_line_break()?;
+                self.push_folded("\\n")
+            }
+            (_, c) => {
+                self.flush_line_break()?;
+                self.push_folded(c.encode_utf8(&mut [0; 4]))
+            }
+        }
+    }
+
+    // Escapes a line break that is not followed by a fold.
+    fn flush_line_break(&mut self) -> fmt::Result {
+        if self.line_break == LineBreak::None {
+            return Ok(());
+        }
+        self.line_break = LineBreak::None;
+        self.push_folded("\\n")
+    }
+
+    // Writes characters that must not be split by a fold.
+    fn push_folded(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let len = c.len_utf8();
+            if self.len + len > LIMIT {
+                self.writer.write_str(FoldWhitespace::Space.line_break())?;
+                self.len = 1;
+            }
+            self.len += len;
+            self.writer.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> fmt::Result {
+        self.flush_line_break()
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for Folder<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let is_plain = self.line_break == LineBreak::None
+            && self.len + s.len() <= LIMIT
+            && !s.bytes().any(|b| b == b'\r' || b == b'\n');
+        if is_plain {
+            self.len += s.len();
+            return self.writer.write_str(s);
+        }
+        s.chars().try_for_each(|c| self.push(c))
+    }
+}
+
+/// Returns the content folded like [`fold`] in a new string. The string is
+/// allocated once with the capacity from [`size`], so this avoids growing the
+/// string repeatedly for long content.
+pub fn fold_into_string(content: &str) -> String {
+    let mut output = String::with_capacity(size(content.len()));
+    fold(&mut output, content).expect("writing to a String never fails");
+    output
+}
+
+/// Writes the content with all folds removed. A fold is a line break (CRLF or
+/// a bare LF) followed by a space or horizontal tab. Any other whitespace is
+/// left untouched.
+pub fn unfold<W: fmt::Write>(writer: &mut W, content: &str) -> fmt::Result {
+    let mut last_end = 0;
+    for fold in folds(content.as_bytes()) {
+        writer.write_str(&content[last_end..fold.start])?;
+        last_end = fold.end;
+    }
+    writer.write_str(&content[last_end..])
+}
+
+/// Removes all folds from raw bytes. Other producers might fold in the middle
+/// of a UTF-8 character which makes it impossible to unfold the content as
+/// `str`.
+pub fn unfold_bytes(content: &[u8]) -> Cow<'_, [u8]> {
+    let mut output = Vec::new();
+    let mut last_end = 0;
+    for fold in folds(content) {
+        output.extend_from_slice(&content[last_end..fold.start]);
+        last_end = fold.end;
+    }
+    if last_end == 0 {
+        Cow::Borrowed(content)
+    } else {
+        output.extend_from_slice(&content[last_end..]);
+        Cow::Owned(output)
+    }
+}
+
+/// Returns the content line unfolded and folded again like [`fold`]. Content
+/// lines of other producers that fold at different positions get the same
+/// folds as the output of this crate. Unfolding the output always restores
+/// the unfolded content line.
+///
+/// # Example
+/// ```
+/// use ics::contentline::refold;
+///
+/// assert_eq!(refold("SUMMARY:Fol\r\n ded"), "SUMMARY:Folded");
+/// ```
+pub fn refold(content: &str) -> String {
+    let mut unfolded = String::with_capacity(content.len());
+    unfold(&mut unfolded, content).expect("writing to a String never fails");
+    fold_into_string(&unfolded)
+}
+
+/// Returns the length in bytes of the longest line between the CRLFs of the
+/// content. Folded content has to be unfolded first to measure the content
+/// lines.
+///
+/// # Example
+/// ```
+/// use ics::contentline::longest_line;
+///
+/// assert_eq!(longest_line("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"), 15);
+/// ```
+pub fn longest_line(content: &str) -> usize {
+    content.split("\r\n").map(str::len).max().unwrap_or(0)
+}
+
+// Counts the bytes of the written content without keeping it.
+#[derive(Default)]
+pub(crate) struct ByteLength(pub(crate) usize);
+
+impl fmt::Write for ByteLength {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+// Folds the written content lines again into lines of at most `limit` bytes.
+// Folded lines are unfolded first, so a content line is kept in memory until
+// its CRLF is written.
+#[cfg(feature = "std")]
+pub(crate) struct Refold<W> {
+    writer: W,
+    limit: usize,
+    line: String,
+    scanned: usize,
+}
+
+#[cfg(feature = "std")]
+impl<W: fmt::Write> Refold<W> {
+    pub(crate) fn new(writer: W, limit: usize) -> Self {
+        Refold {
+            writer,
+            limit,
+            line: String::new(),
+            scanned: 0,
+        }
+    }
+
+    // Writes the remaining content and returns the inner writer.
+    pub(crate) fn finish(mut self) -> Result<W, fmt::Error> {
+        let line = self.line.trim_end_matches("\r\n");
+        fold_with_width(&mut self.writer, line, self.limit)?;
+        if line.len() < self.line.len() {
+            self.writer.write_str("\r\n")?;
+        }
+        Ok(self.writer)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: fmt::Write> fmt::Write for Refold<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.line.push_str(s);
+        while let Some(index) = self.line[self.scanned..].find("\r\n") {
+            let end = self.scanned + index;
+            match self.line.as_bytes().get(end + 2) {
+                // The next byte decides whether the line break is a fold.
+                None => {
+                    self.scanned = end;
+                    return Ok(());
+                }
+                Some(b' ') | Some(b'\t') => {
+                    self.line.drain(end..end + 3);
+                    self.scanned = end;
+                }
+                Some(_) => {
+                    fold_with_width(&mut self.writer, &self.line[..end], self.limit)?;
+                    self.writer.write_str("\r\n")?;
+                    self.line.drain(..end + 2);
+                    self.scanned = 0;
+                }
+            }
+        }
+        self.scanned = self.line.len() - if self.line.ends_with('\r') { 1 } else { 0 };
+        Ok(())
+    }
+}
+
+// Measures the longest content line of the written content while it is
+// written, so that it does not need to be unfolded in memory.
+#[derive(Default)]
+pub(crate) struct UnfoldedLength {
+    current: usize,
+    longest: usize,
+    after_cr: bool,
+    after_lf: bool,
+}
+
+impl UnfoldedLength {
+    pub(crate) fn longest(&self) -> usize {
+        let current = self.current + if self.after_cr { 1 } else { 0 };
+        self.longest.max(current)
+    }
+}
+
+impl fmt::Write for UnfoldedLength {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &b in s.as_bytes() {
+            if self.after_lf {
+                self.after_lf = false;
+                if b == b' ' || b == b'\t' {
+                    continue;
+                }
+                self.longest = self.longest.max(self.current);
+                self.current = 0;
+            }
+            if self.after_cr {
+                self.after_cr = false;
+                if b == b'\n' {
+                    self.after_lf = true;
+                    continue;
+                }
+                self.current += 1;
+            }
+            match b {
+                b'\r' => self.after_cr = true,
+                b'\n' => self.after_lf = true,
+                _ => self.current += 1,
+            }
+        }
+        Ok(())
+    }
+}
+
+// Finds the byte ranges of line breaks followed by a space or horizontal tab.
+fn folds(content: &[u8]) -> impl Iterator<Item = Range<usize>> + '_ {
+    content
+        .iter()
+        .enumerate()
+        .filter(move |&(index, &b)| {
+            b == b'\n'
+                && (content.get(index + 1) == Some(&b' ') || content.get(index + 1) == Some(&b'\t'))
+        })
+        .map(move |(index, _)| {
+            if index > 0 && content[index - 1] == b'\r' {
+                index - 1..index + 2
+            } else {
+                index..index + 2
+            }
+        })
+}
 
 fn next_boundary(input: &str, limit: usize) -> usize {
     let input = input.as_bytes();
     if limit >= input.len() {
         return input.len();
     }
-    match input[..=limit].iter().rposition(|&i| i < 128 || i >= 192) {
-        Some(0) | None => input.len(),
+    match input[..=limit]
+        .iter()
+        .rposition(|&i| !(128..192).contains(&i))
+    {
+        // The first character is longer than the limit, so it gets a line of
+        // its own.
+        Some(0) | None => input[1..]
+            .iter()
+            .position(|&i| !(128..192).contains(&i))
+            .map_or(input.len(), |index| index + 1),
         Some(index) => index,
     }
 }
 
-// Calculates the new estimated text length after inserting line breaks
+/// Calculates the new estimated text length after inserting line breaks.
 pub fn size(len: usize) -> usize {
+    if len < 2 {
+        return len;
+    }
     len + ((len - 2) / (LIMIT - 1)) * 3
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{fold, size};
+    use super::{
+        fold, fold_at_whitespace, fold_into_string, fold_property, fold_with, fold_with_width,
+        longest_line, next_boundary, refold, size, unfold, unfold_bytes, FoldWhitespace,
+        UnfoldedLength,
+    };
+    use core::fmt::Write;
 
     #[test]
     fn no_linebreak() {
@@ -73,6 +565,64 @@ mod tests {
         assert_eq!(line, expected);
     }
 
+    // Generates pseudo-random content from characters of every UTF-8 length
+    // and characters that need escaping. The sequence is fixed, so failures
+    // can be reproduced.
+    fn random_contents() -> impl Iterator<Item = String> {
+        const CHARS: [char; 8] = ['a', 'Z', ' ', ',', '\\', 'é', '老', '😀'];
+        let mut state: u32 = 0x9E37_79B9;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as usize
+        };
+        (0..2000).map(move |_| {
+            let len = next() % 400;
+            (0..len).map(|_| CHARS[next() % CHARS.len()]).collect()
+        })
+    }
+
+    #[test]
+    fn fold_roundtrip() {
+        for content in random_contents() {
+            let folded = fold_into_string(&content);
+            assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+            let mut unfolded = String::new();
+            unfold(&mut unfolded, &folded).unwrap();
+            assert_eq!(unfolded, content);
+            assert_eq!(refold(&folded), folded);
+
+            for &limit in &[3, 10, 76] {
+                let mut folded = String::new();
+                fold_with_width(&mut folded, &content, limit).unwrap();
+                // A character longer than the limit is not split.
+                assert!(folded
+                    .split("\r\n")
+                    .all(|line| line.len() <= limit || line.trim_start().chars().count() == 1));
+                let mut unfolded = String::new();
+                unfold(&mut unfolded, &folded).unwrap();
+                assert_eq!(unfolded, content);
+                assert_eq!(refold(&folded), fold_into_string(&content));
+            }
+
+            let mut folded = String::new();
+            fold_at_whitespace(&mut folded, &content, 20).unwrap();
+            let mut unfolded = String::new();
+            unfold(&mut unfolded, &folded).unwrap();
+            assert_eq!(unfolded, content);
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(next_boundary("abcdef", 3), 3);
+        assert_eq!(next_boundary("老abc", 3), 3);
+        assert_eq!(next_boundary("老abc", 2), 3);
+        assert_eq!(next_boundary("a老b", 2), 1);
+        assert_eq!(next_boundary("abc", 3), 3);
+    }
+
     #[test]
     fn multibytes_with_space() {
         let content =
@@ -96,6 +646,76 @@ mod tests {
     }
 
     #[test]
+    fn tab() {
+        let content = "Content lines that have a fixed length over 75 bytes should be line folded with CRLF and whitespace.";
+        let mut line = String::with_capacity(size(content.len()));
+        fold_with(&mut line, content, FoldWhitespace::Tab).unwrap();
+        let expected = "Content lines that have a fixed length over 75 bytes should be line folded \r\n\twith CRLF and whitespace.";
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn unfold_lines() {
+        let content = "The quick brown fox jumps over the lazy dog. The quick brown fox jumps over\r\n  the lazy cog. The quick brown fox jumps over the lazy hog. The quick brow\r\n n fox jumps over the lazy log.";
+        let mut line = String::with_capacity(content.len());
+        unfold(&mut line, content).unwrap();
+        let expected = "The quick brown fox jumps over the lazy dog. The quick brown fox jumps over the lazy cog. The quick brown fox jumps over the lazy hog. The quick brown fox jumps over the lazy log.";
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn unfold_tab_and_bare_line_feed() {
+        let content =
+            "DESCRIPTION:This is a lo\r\n\tng description\n  that exists on \n\ta long line.";
+        let mut line = String::new();
+        unfold(&mut line, content).unwrap();
+        let expected = "DESCRIPTION:This is a long description that exists on a long line.";
+
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn unfold_keeps_literal_whitespace() {
+        let content = "SUMMARY:Two  spaces, a\ttab and\r\nno fold";
+        let mut line = String::new();
+        unfold(&mut line, content).unwrap();
+
+        assert_eq!(line, content);
+    }
+
+    #[test]
+    fn unfold_multibytes() {
+        let content =
+            "Content lines shouldn't be folded in the middle of a UTF-8 character! 老\r\n 虎.";
+        let mut line = String::new();
+        unfold(&mut line, content).unwrap();
+
+        assert_eq!(
+            line,
+            "Content lines shouldn't be folded in the middle of a UTF-8 character! 老虎."
+        );
+    }
+
+    #[test]
+    fn unfold_split_multibytes() {
+        let mut content = "老".as_bytes()[..2].to_vec();
+        content.extend_from_slice(b"\r\n ");
+        content.extend_from_slice(&"老".as_bytes()[2..]);
+
+        assert_eq!(&*unfold_bytes(&content), "老".as_bytes());
+    }
+
+    #[test]
+    fn small_sizes() {
+        assert_eq!(0, size(0));
+        assert_eq!(1, size(1));
+        assert_eq!(2, size(2));
+    }
+
+    #[test]
+    #[allow(clippy::identity_op, clippy::erasing_op)]
     fn sizes() {
         assert_eq!(12 + 0 * 3, size(12));
         assert_eq!(75 + 0 * 3, size(75));
@@ -104,7 +724,7 @@ mod tests {
         assert_eq!(148 + 1 * 3, size(148));
         assert_eq!(149 + 1 * 3, size(149));
         assert_eq!(150 + 2 * 3, size(150));
-        
+
         assert_eq!(222 + 2 * 3, size(222));
         assert_eq!(223 + 2 * 3, size(223));
         assert_eq!(224 + 3 * 3, size(224));
@@ -113,4 +733,95 @@ mod tests {
         assert_eq!(297 + 3 * 3, size(297));
         assert_eq!(298 + 4 * 3, size(298));
     }
+
+    #[test]
+    fn into_string() {
+        let content =
+            "Content lines that are fairly long compared to the limit are folded several times. \
+                       That is why the capacity must be large enough for all line breaks.";
+        let folded = fold_into_string(content);
+        let mut expected = String::new();
+        fold(&mut expected, content).unwrap();
+        assert_eq!(folded, expected);
+        assert_eq!(folded.len(), folded.capacity());
+    }
+
+    #[test]
+    fn at_whitespace() {
+        let content = "Content lines that are longer than the limit are folded before the last space of a line.";
+        let mut line = String::new();
+        fold_at_whitespace(&mut line, content, 16).unwrap();
+        assert_eq!(
+            line,
+            "Content lines that are longer than the limit are folded before the last\r\n  space of a line."
+        );
+        let mut unfolded = String::new();
+        unfold(&mut unfolded, &line).unwrap();
+        assert_eq!(unfolded, content);
+    }
+
+    #[test]
+    fn at_whitespace_long_word() {
+        let content =
+            "A-very-long-token-without-any-spaces-which-cannot-be-folded-at-a-space-at-all";
+        let mut expected = String::new();
+        fold(&mut expected, content).unwrap();
+        let mut line = String::new();
+        fold_at_whitespace(&mut line, content, 16).unwrap();
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn line_breaks_in_content() {
+        let mut line = String::new();
+        fold(&mut line, "DESCRIPTION:One\nTwo\rThree\r\nFour").unwrap();
+        assert_eq!(line, "DESCRIPTION:One\\nTwo\\nThree\\nFour");
+
+        let content = "DESCRIPTION:This line was folded before it was passed to the fold function.";
+        let mut folded = String::new();
+        fold(&mut folded, content).unwrap();
+        let mut line = String::new();
+        fold(&mut line, &folded).unwrap();
+        assert_eq!(line, folded);
+    }
+
+    #[test]
+    fn width() {
+        let mut line = String::new();
+        fold_with_width(&mut line, "SUMMARY:Folded at ten bytes", 10).unwrap();
+        assert_eq!(line, "SUMMARY:Fo\r\n lded at t\r\n en bytes");
+
+        let mut line = String::new();
+        fold_with_width(&mut line, "SUMMARY", 1).unwrap();
+        assert_eq!(line, "SUM\r\n MA\r\n RY");
+    }
+
+    #[test]
+    fn property() {
+        let value = "The quick brown fox jumps over the lazy dog.\r\nThe quick brown fox jumps over the lazy cog. 老虎\r\n  the end";
+        let parameters =
+            crate::parameters!("ALTREP" => "cid:part1.0001@example.org"; "LANGUAGE" => "en");
+        let mut line = String::new();
+        fold_property(&mut line, "DESCRIPTION", &parameters, value).unwrap();
+
+        let content = format!(
+            "DESCRIPTION;ALTREP=\"cid:part1.0001@example.org\";LANGUAGE=en:{}",
+            value
+        );
+        let mut expected = String::new();
+        fold(&mut expected, &content).unwrap();
+        assert_eq!(line, expected);
+    }
+
+    #[test]
+    fn longest_lines() {
+        let content = "SUMMARY:Short\r\nDESCRIPTION:A longer\r\n  line\r\n";
+        assert_eq!(longest_line(content), 20);
+        assert_eq!(longest_line(""), 0);
+
+        let mut length = UnfoldedLength::default();
+        write!(length, "SUMMARY:Short\r\nDESCRIPTION:A longer\r").unwrap();
+        write!(length, "\n  line\r\n").unwrap();
+        assert_eq!(length.longest(), 25);
+    }
 }
@@ -0,0 +1,231 @@
+//! Differences between two versions of an iCalendar object.
+use crate::components::Property;
+use crate::ical::{CalendarComponent, Event, ICalendar};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+impl<'a> ICalendar<'a> {
+    /// Compares the events of this iCalendar object with the events of a newer
+    /// version of it.
+    ///
+    /// Events are matched by their `UID` and `RECURRENCE-ID`, so recurrence
+    /// instances are compared separately. Events without a `UID` are ignored.
+    /// A matched event is modified if its properties differ. Sub-components
+    /// like alarms are not compared. If a calendar contains an instance
+    /// several times, which [`ICalendar::validate()`] reports, the occurrences
+    /// are paired in their order and the surplus ones count as added or
+    /// removed.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::Summary;
+    /// use ics::{Event, ICalendar, PropertyChange};
+    ///
+    /// let mut old = ICalendar::new("2.0", "ics-rs");
+    /// old.add_event(Event::new("1", "19960704T120000Z"));
+    /// let mut new = old.clone();
+    /// new.events_mut().next().unwrap().push(Summary::new("Meeting"));
+    /// new.add_event(Event::new("2", "19960704T120000Z"));
+    ///
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.added().count(), 1);
+    /// let modified = diff.modified().next().unwrap();
+    /// assert_eq!(modified.uid(), "1");
+    /// match modified.changes() {
+    ///     [PropertyChange::Added(summary)] => assert_eq!(summary.value(), "Meeting"),
+    ///     _ => panic!("expected an added SUMMARY"),
+    /// }
+    /// ```
+    pub fn diff<'b>(&'b self, other: &'b ICalendar<'a>) -> CalendarDiff<'b, 'a> {
+        let old = index(self);
+        let new = index(other);
+        let mut diff = CalendarDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        };
+        let mut seen = BTreeMap::new();
+        for (instance, event) in instances(self) {
+            let occurrence = count(&mut seen, instance);
+            match new.get(&instance).and_then(|events| events.get(occurrence)) {
+                Some(&other) => {
+                    let changes = property_changes(&event.properties, &other.properties);
+                    if !changes.is_empty() {
+                        diff.modified.push(EventDiff {
+                            uid: instance.0,
+                            recurrence_id: instance.1,
+                            changes,
+                        });
+                    }
+                }
+                None => diff.removed.push(event),
+            }
+        }
+        seen.clear();
+        for (instance, event) in instances(other) {
+            let occurrence = count(&mut seen, instance);
+            if old.get(&instance).map_or(0, Vec::len) <= occurrence {
+                diff.added.push(event);
+            }
+        }
+        diff
+    }
+}
+
+/// The differences between the events of two versions of an [`ICalendar`]
+/// object as returned by [`ICalendar::diff()`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CalendarDiff<'b, 'a> {
+    added: Vec<&'b Event<'a>>,
+    removed: Vec<&'b Event<'a>>,
+    modified: Vec<EventDiff<'b, 'a>>,
+}
+
+impl<'b, 'a> CalendarDiff<'b, 'a> {
+    /// Returns an iterator over the events that only the newer version
+    /// contains.
+    pub fn added(&self) -> impl Iterator<Item = &'b Event<'a>> + '_ {
+        self.added.iter().copied()
+    }
+
+    /// Returns an iterator over the events that only the older version
+    /// contains.
+    pub fn removed(&self) -> impl Iterator<Item = &'b Event<'a>> + '_ {
+        self.removed.iter().copied()
+    }
+
+    /// Returns an iterator over the events whose properties changed.
+    pub fn modified(&self) -> impl Iterator<Item = &EventDiff<'b, 'a>> {
+        self.modified.iter()
+    }
+
+    /// Returns whether both versions contain the same events.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// The changed properties of an event that both versions of an [`ICalendar`]
+/// object contain
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EventDiff<'b, 'a> {
+    uid: &'b str,
+    recurrence_id: Option<&'b str>,
+    changes: Vec<PropertyChange<'b, 'a>>,
+}
+
+impl<'b, 'a> EventDiff<'b, 'a> {
+    /// Returns the value of the `UID` property of the event.
+    pub fn uid(&self) -> &'b str {
+        self.uid
+    }
+
+    /// Returns the value of the `RECURRENCE-ID` property if the event is a
+    /// recurrence instance.
+    pub fn recurrence_id(&self) -> Option<&'b str> {
+        self.recurrence_id
+    }
+
+    /// Returns the changes of the properties in the order the properties
+    /// first appear in the older and then the newer version.
+    pub fn changes(&self) -> &[PropertyChange<'b, 'a>] {
+        &self.changes
+    }
+}
+
+/// A change of a property between two versions of a component
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PropertyChange<'b, 'a> {
+    /// The property only exists in the newer version.
+    Added(&'b Property<'a>),
+    /// The property only exists in the older version.
+    Removed(&'b Property<'a>),
+    /// The value or parameters of a property that occurs once in both versions
+    /// changed.
+    Modified {
+        /// The property of the older version
+        old: &'b Property<'a>,
+        /// The property of the newer version
+        new: &'b Property<'a>,
+    },
+}
+
+type Instance<'b> = (&'b str, Option<&'b str>);
+
+fn instances<'b, 'a>(
+    calendar: &'b ICalendar<'a>,
+) -> impl Iterator<Item = (Instance<'b>, &'b Event<'a>)> {
+    calendar
+        .components
+        .iter()
+        .filter_map(|component| match component {
+            CalendarComponent::Event(event) => component.instance().map(|id| (id, event)),
+            _ => None,
+        })
+}
+
+// Groups the events by instance in the order of the calendar.
+fn index<'b, 'a>(calendar: &'b ICalendar<'a>) -> BTreeMap<Instance<'b>, Vec<&'b Event<'a>>> {
+    let mut index = BTreeMap::new();
+    for (instance, event) in instances(calendar) {
+        index.entry(instance).or_insert_with(Vec::new).push(event);
+    }
+    index
+}
+
+// Returns how often the instance was seen before.
+fn count<'b>(seen: &mut BTreeMap<Instance<'b>, usize>, instance: Instance<'b>) -> usize {
+    let count = seen.entry(instance).or_insert(0);
+    *count += 1;
+    *count - 1
+}
+
+// Properties are compared by name. A property that occurs once in both
+// versions is modified, otherwise the properties that only one version
+// contains are added or removed.
+fn property_changes<'b, 'a>(
+    old: &'b [Property<'a>],
+    new: &'b [Property<'a>],
+) -> Vec<PropertyChange<'b, 'a>> {
+    let mut names: Vec<&str> = Vec::new();
+    for property in old.iter().chain(new) {
+        if !names
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(&property.key))
+        {
+            names.push(&property.key);
+        }
+    }
+
+    let mut changes = Vec::new();
+    for name in names {
+        let old: Vec<_> = old
+            .iter()
+            .filter(|property| property.key.eq_ignore_ascii_case(name))
+            .collect();
+        let new: Vec<_> = new
+            .iter()
+            .filter(|property| property.key.eq_ignore_ascii_case(name))
+            .collect();
+        match (old.as_slice(), new.as_slice()) {
+            (&[old], &[new]) if old != new => changes.push(PropertyChange::Modified { old, new }),
+            (old, new) => {
+                for property in old
+                    .iter()
+                    .copied()
+                    .filter(|property| !new.contains(property))
+                {
+                    changes.push(PropertyChange::Removed(property));
+                }
+                for property in new
+                    .iter()
+                    .copied()
+                    .filter(|property| !old.contains(property))
+                {
+                    changes.push(PropertyChange::Added(property));
+                }
+            }
+        }
+    }
+    changes
+}
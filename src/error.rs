@@ -0,0 +1,125 @@
+//! The error type that unifies all failures of the library.
+use crate::parser::ParseError;
+use crate::properties::{RRuleError, URLError};
+use crate::validation::ValidationError;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// The error type for all operations of the library
+///
+/// Every specific error type can be converted into it, so `?` can be used
+/// uniformly in functions that return `Result<_, ics::Error>`. The `Display`
+/// implementation only names the kind of failure, the specific error is
+/// available with `source()`.
+///
+/// Only functions that can fail in more than one way return this type, like
+/// [`ICalendar::save_file_validated()`](crate::ICalendar::save_file_validated).
+/// Reading, writing and parsing keep returning `io::Error` and
+/// [`ParseError`] so that existing callers don't break, but these errors
+/// convert into this type with `?`.
+///
+/// More variants may be added in future versions and the `Io` variant only
+/// exists with the `std` feature, which another crate in the dependency graph
+/// can enable. A `match` on this type should therefore always have a wildcard
+/// arm.
+///
+/// # Example
+/// ```
+/// use ics::properties::{Frequency, RRuleBuilder, URL};
+/// use ics::Event;
+///
+/// fn weekly(url: &str) -> Result<Event<'_>, ics::Error> {
+///     let mut event = Event::new("1", "19970901T130000Z");
+///     event.push(RRuleBuilder::new().freq(Frequency::Weekly).build()?);
+///     event.push(URL::parse(url)?);
+///     Ok(event)
+/// }
+///
+/// assert!(weekly("https://example.com/meeting").is_ok());
+/// assert!(match weekly("example.com/meeting") {
+///     Err(ics::Error::URL(_)) => true,
+///     _ => false,
+/// });
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// Formatting a content line failed.
+    Fmt(fmt::Error),
+    /// The iCalendar object violates the specification.
+    Validation(ValidationError),
+    /// The text representation of an iCalendar object is invalid.
+    Parse(ParseError),
+    /// A recurrence rule could not be built.
+    RRule(RRuleError),
+    /// A URL does not start with a URI scheme.
+    URL(URLError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            #[cfg(feature = "std")]
+            Error::Io(_) => "I/O error",
+            Error::Fmt(_) => "formatting failed",
+            Error::Validation(_) => "invalid iCalendar object",
+            Error::Parse(_) => "parsing failed",
+            Error::RRule(_) => "invalid recurrence rule",
+            Error::URL(_) => "invalid URL",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Fmt(error) => Some(error),
+            Error::Validation(error) => Some(error),
+            Error::Parse(error) => Some(error),
+            Error::RRule(error) => Some(error),
+            Error::URL(error) => Some(error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(error: fmt::Error) -> Self {
+        Error::Fmt(error)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(error: ValidationError) -> Self {
+        Error::Validation(error)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+impl From<RRuleError> for Error {
+    fn from(error: RRuleError) -> Self {
+        Error::RRule(error)
+    }
+}
+
+impl From<URLError> for Error {
+    fn from(error: URLError) -> Self {
+        Error::URL(error)
+    }
+}
@@ -0,0 +1,488 @@
+//! Expansion of recurrence rules into the dates of their occurrences.
+//!
+//! Only a part of the recurrence rules can be expanded, which covers most
+//! rules that calendar applications create:
+//! - `FREQ` is `DAILY`, `WEEKLY` or `MONTHLY`
+//! - `INTERVAL`, `COUNT`, `UNTIL` and `WKST`
+//! - `BYDAY` with weekdays, and for monthly rules also with an ordinal like
+//!   `1MO` or `-1FR`
+//!
+//! Rules with other frequencies or rule parts like `BYMONTHDAY` or `BYSETPOS`
+//! are not supported. Time zones are not taken into account either, so the
+//! date-times are calculated in the time of `DTSTART`.
+//!
+//! # Example
+//! ```
+//! use ics::expand::expand_rrule;
+//! use ics::properties::RRule;
+//!
+//! let rrule = RRule::new("FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4");
+//! let occurrences = expand_rrule("19970902T090000", &rrule, 10);
+//! assert_eq!(
+//!     occurrences,
+//!     [
+//!         "19970902T090000",
+//!         "19970904T090000",
+//!         "19970909T090000",
+//!         "19970911T090000"
+//!     ]
+//! );
+//! ```
+use crate::properties::RRule;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Returns the start dates of the occurrences of a recurrence rule. The first
+/// occurrence is always `dtstart`, which must be a `DATE` like `19970902` or a
+/// `DATE-TIME` like `19970902T090000` or `19970902T090000Z`. The occurrences
+/// are written in the same format.
+///
+/// At most `limit` occurrences are returned, which also limits rules without
+/// `COUNT` or `UNTIL`. An empty list is returned if `dtstart` is invalid or the
+/// rule contains parts that are not supported, see the module documentation.
+pub fn expand_rrule(dtstart: &str, rrule: &RRule, limit: usize) -> Vec<String> {
+    let start = match DateTime::parse(dtstart) {
+        Some(start) => start,
+        None => return Vec::new(),
+    };
+    let rule = match Rule::parse(rrule.value()) {
+        Some(rule) => rule,
+        None => return Vec::new(),
+    };
+    let limit = rule.count.map_or(limit, |count| count.min(limit));
+    let is_valid = |date: &DateTime| rule.until.as_ref().map_or(true, |until| date <= until);
+
+    let mut occurrences = Vec::new();
+    if limit == 0 || !is_valid(&start) {
+        return occurrences;
+    }
+    occurrences.push(start.format(dtstart));
+
+    let mut period = 0;
+    while occurrences.len() < limit {
+        let days = rule.days(&start, period);
+        period += rule.interval;
+        if period > MAX_DAY || days.first().map_or(false, |&day| day > MAX_DAY) {
+            break;
+        }
+        for day in days.into_iter().filter(|&day| day > start.day) {
+            let date = DateTime { day, ..start };
+            if !is_valid(&date) {
+                return occurrences;
+            }
+            occurrences.push(date.format(dtstart));
+            if occurrences.len() == limit {
+                break;
+            }
+        }
+    }
+    occurrences
+}
+
+// The last day that can be written with four digits of the year.
+const MAX_DAY: i64 = 2_932_896;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct DateTime {
+    // Days since 1970-01-01
+    day: i64,
+    // Seconds of the day or `None` for a date
+    time: Option<u32>,
+}
+
+impl DateTime {
+    fn parse(value: &str) -> Option<Self> {
+        let value = if value.ends_with('Z') {
+            &value[..value.len() - 1]
+        } else {
+            value
+        };
+        let (date, time) = match value.find('T') {
+            Some(index) => (&value[..index], Some(&value[index + 1..])),
+            None => (value, None),
+        };
+        if date.len() != 8 || !date.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let year = date[..4].parse().ok()?;
+        let month = date[4..6].parse().ok()?;
+        let day = date[6..].parse().ok()?;
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        let time = match time {
+            Some(time) if time.len() == 6 && time.bytes().all(|b| b.is_ascii_digit()) => {
+                let hour: u32 = time[..2].parse().ok()?;
+                let minute: u32 = time[2..4].parse().ok()?;
+                let second: u32 = time[4..].parse().ok()?;
+                if hour > 23 || minute > 59 || second > 60 {
+                    return None;
+                }
+                Some(hour * 3600 + minute * 60 + second)
+            }
+            Some(_) => return None,
+            None => None,
+        };
+        Some(DateTime {
+            day: days_from_civil(year, month, day),
+            time,
+        })
+    }
+
+    // Writes the date-time in the format of `template`.
+    fn format(&self, template: &str) -> String {
+        let (year, month, day) = civil_from_days(self.day);
+        match self.time {
+            Some(time) => format!(
+                "{:04}{:02}{:02}T{:02}{:02}{:02}{}",
+                year,
+                month,
+                day,
+                time / 3600,
+                time / 60 % 60,
+                time % 60,
+                if template.ends_with('Z') { "Z" } else { "" }
+            ),
+            None => format!("{:04}{:02}{:02}", year, month, day),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug)]
+struct Rule {
+    frequency: Frequency,
+    interval: i64,
+    count: Option<usize>,
+    until: Option<DateTime>,
+    // Weekdays starting with Monday as 0 and the optional ordinal
+    by_day: Vec<(Option<i64>, i64)>,
+    week_start: i64,
+}
+
+impl Rule {
+    fn parse(value: &str) -> Option<Self> {
+        let mut frequency = None;
+        let mut rule = Rule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: Vec::new(),
+            week_start: 0,
+        };
+        for part in value.split(';') {
+            let mut split = part.splitn(2, '=');
+            let name = split.next()?;
+            let value = split.next()?;
+            if name.eq_ignore_ascii_case("FREQ") {
+                frequency = Some(match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    _ => return None,
+                });
+            } else if name.eq_ignore_ascii_case("INTERVAL") {
+                rule.interval = value.parse().ok().filter(|&interval| interval > 0)?;
+            } else if name.eq_ignore_ascii_case("COUNT") {
+                rule.count = Some(value.parse().ok()?);
+            } else if name.eq_ignore_ascii_case("UNTIL") {
+                let mut until = DateTime::parse(value)?;
+                // A date includes the whole day.
+                until.time = Some(until.time.unwrap_or(86_400));
+                rule.until = Some(until);
+            } else if name.eq_ignore_ascii_case("WKST") {
+                rule.week_start = weekday(value)?;
+            } else if name.eq_ignore_ascii_case("BYDAY") {
+                for day in value.split(',') {
+                    let index = day.len().checked_sub(2)?;
+                    if !day.is_char_boundary(index) {
+                        return None;
+                    }
+                    let (ordinal, day) = day.split_at(index);
+                    // A month has at most five of each weekday.
+                    let ordinal = if ordinal.is_empty() {
+                        None
+                    } else {
+                        Some(
+                            ordinal
+                                .parse()
+                                .ok()
+                                .filter(|&n: &i64| n != 0 && (-5..=5).contains(&n))?,
+                        )
+                    };
+                    rule.by_day.push((ordinal, weekday(day)?));
+                }
+            } else {
+                return None;
+            }
+        }
+        rule.frequency = frequency?;
+        let has_ordinal = rule.by_day.iter().any(|(ordinal, _)| ordinal.is_some());
+        if has_ordinal && rule.frequency != Frequency::Monthly {
+            return None;
+        }
+        Some(rule)
+    }
+
+    // Returns the sorted days of a period, which is a number of days, weeks or
+    // months after the one of `start`.
+    fn days(&self, start: &DateTime, period: i64) -> Vec<i64> {
+        let matches = |day: i64| {
+            self.by_day.is_empty() || self.by_day.iter().any(|&(_, d)| d == weekday_of(day))
+        };
+        let mut days = match self.frequency {
+            Frequency::Daily => {
+                let day = start.day + period;
+                if matches(day) {
+                    vec![day]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let offset = (weekday_of(start.day) - self.week_start + 7) % 7;
+                let first = start.day - offset + period * 7;
+                if self.by_day.is_empty() {
+                    vec![start.day + period * 7]
+                } else {
+                    (first..first + 7).filter(|&day| matches(day)).collect()
+                }
+            }
+            Frequency::Monthly => {
+                let (year, month, day) = civil_from_days(start.day);
+                let months = year * 12 + i64::from(month) - 1 + period;
+                let year = div_floor(months, 12);
+                let month = (months - year * 12 + 1) as u32;
+                let length = days_in_month(year, month);
+                let first = days_from_civil(year, month, 1);
+                if self.by_day.is_empty() {
+                    // Months without the day of the month are skipped.
+                    if day <= length {
+                        vec![first + i64::from(day) - 1]
+                    } else {
+                        Vec::new()
+                    }
+                } else {
+                    self.monthly_days(first, i64::from(length))
+                }
+            }
+        };
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    fn monthly_days(&self, first: i64, length: i64) -> Vec<i64> {
+        let mut days = Vec::new();
+        for &(ordinal, weekday) in &self.by_day {
+            let offset = (weekday - weekday_of(first) + 7) % 7;
+            let all = (first + offset..first + length).step_by(7);
+            match ordinal {
+                None => days.extend(all),
+                Some(n) if n > 0 => days.extend(all.skip(n as usize - 1).take(1)),
+                Some(n) => {
+                    let all: Vec<_> = all.collect();
+                    if let Some(index) = all.len().checked_sub(-n as usize) {
+                        days.push(all[index]);
+                    }
+                }
+            }
+        }
+        days
+    }
+}
+
+// Returns the weekday starting with Monday as 0.
+fn weekday(value: &str) -> Option<i64> {
+    let weekday = match value.to_ascii_uppercase().as_str() {
+        "MO" => 0,
+        "TU" => 1,
+        "WE" => 2,
+        "TH" => 3,
+        "FR" => 4,
+        "SA" => 5,
+        "SU" => 6,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+fn weekday_of(day: i64) -> i64 {
+    // 1970-01-01 was a Thursday.
+    day + 3 - div_floor(day + 3, 7) * 7
+}
+
+fn div_floor(a: i64, b: i64) -> i64 {
+    if a < 0 {
+        (a - b + 1) / b
+    } else {
+        a / b
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Converts a date of the proleptic Gregorian calendar to the days since
+// 1970-01-01. (see http://howardhinnant.github.io/date_algorithms.html)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = div_floor(year, 400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = div_floor(days, 146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil, expand_rrule};
+    use crate::properties::RRule;
+
+    fn expand(dtstart: &str, rrule: &str) -> Vec<String> {
+        expand_rrule(dtstart, &RRule::new(rrule), 100)
+    }
+
+    #[test]
+    fn civil_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn daily() {
+        let dates = expand("19970902T090000Z", "FREQ=DAILY;INTERVAL=10;COUNT=3");
+        assert_eq!(
+            dates,
+            ["19970902T090000Z", "19970912T090000Z", "19970922T090000Z"]
+        );
+        let dates = expand("19971229", "FREQ=DAILY;UNTIL=19980102");
+        assert_eq!(
+            dates,
+            ["19971229", "19971230", "19971231", "19980101", "19980102"]
+        );
+        let dates = expand("19970905T090000", "FREQ=DAILY;BYDAY=MO,FR;COUNT=3");
+        assert_eq!(
+            dates,
+            ["19970905T090000", "19970908T090000", "19970912T090000"]
+        );
+    }
+
+    #[test]
+    fn weekly() {
+        let dates = expand("19970902T090000", "FREQ=WEEKLY;UNTIL=19970916T090000");
+        assert_eq!(
+            dates,
+            ["19970902T090000", "19970909T090000", "19970916T090000"]
+        );
+        // Example of RFC5545 3.8.5.3. with the week starting on Sunday
+        let dates = expand(
+            "19970805T090000",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU",
+        );
+        assert_eq!(
+            dates,
+            [
+                "19970805T090000",
+                "19970817T090000",
+                "19970819T090000",
+                "19970831T090000"
+            ]
+        );
+        let dates = expand(
+            "19970805T090000",
+            "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO",
+        );
+        assert_eq!(
+            dates,
+            [
+                "19970805T090000",
+                "19970810T090000",
+                "19970819T090000",
+                "19970824T090000"
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly() {
+        let dates = expand("19970905T090000", "FREQ=MONTHLY;COUNT=4;BYDAY=1FR");
+        assert_eq!(
+            dates,
+            [
+                "19970905T090000",
+                "19971003T090000",
+                "19971107T090000",
+                "19971205T090000"
+            ]
+        );
+        let dates = expand("19970928T090000", "FREQ=MONTHLY;COUNT=3;BYDAY=-1SU");
+        assert_eq!(
+            dates,
+            ["19970928T090000", "19971026T090000", "19971130T090000"]
+        );
+        let dates = expand("20240129", "FREQ=MONTHLY;COUNT=3;BYDAY=5MO");
+        assert_eq!(dates, ["20240129", "20240429", "20240729"]);
+        let dates = expand("20240101", "FREQ=MONTHLY;COUNT=2;BYDAY=-5MO");
+        assert_eq!(dates, ["20240101", "20240401"]);
+        let dates = expand("20240131", "FREQ=MONTHLY;COUNT=3");
+        assert_eq!(dates, ["20240131", "20240331", "20240531"]);
+    }
+
+    #[test]
+    fn limit() {
+        let dates = expand_rrule("20240101", &RRule::new("FREQ=DAILY"), 2);
+        assert_eq!(dates, ["20240101", "20240102"]);
+        assert!(expand_rrule("20240101", &RRule::new("FREQ=DAILY"), 0).is_empty());
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(expand("20240101", "FREQ=YEARLY").is_empty());
+        assert!(expand("20240101", "FREQ=MONTHLY;BYMONTHDAY=1").is_empty());
+        assert!(expand("20240101", "FREQ=WEEKLY;BYDAY=1MO").is_empty());
+        assert!(expand("2024-01-01", "FREQ=DAILY").is_empty());
+        assert!(expand("20240101", "FREQ=WEEKLY;BYDAY=Mü").is_empty());
+        assert!(expand("20240101", "FREQ=MONTHLY;BYDAY=0MO").is_empty());
+        assert!(expand("20240101", "FREQ=MONTHLY;BYDAY=6MO").is_empty());
+        assert!(expand("20240101", "FREQ=MONTHLY;BYDAY=-6MO").is_empty());
+        assert!(expand("20240101", "FREQ=MONTHLY;BYDAY=-9223372036854775808MO").is_empty());
+    }
+}
@@ -1,12 +1,35 @@
 use crate::components::{Component, Property};
+use crate::contentline;
+#[cfg(feature = "std")]
+use crate::error::Error;
+use crate::parameters::Related;
+use crate::parser::{self, ParseError};
+#[cfg(feature = "uuid")]
+use crate::properties::random_uid;
+#[cfg(feature = "rfc7986")]
+use crate::properties::Name;
 use crate::properties::{
-    Action, Description, DtStamp, DtStart, ProdID, Summary, Trigger, TzID, TzOffsetFrom,
-    TzOffsetTo, Version, UID,
+    Action, CalScale, Description, DtStamp, DtStart, Method, ParticipantType, ProdID, RRule,
+    Summary, Trigger, TzID, TzName, TzOffsetFrom, TzOffsetTo, Version, XWrCalDesc, XWrCalName, UID,
 };
-use std::borrow::Cow;
-use std::fmt;
+use crate::util::escape_text;
+use crate::validation::{self, ValidationError};
+#[cfg(feature = "std")]
+use crate::values::DateTime;
+use crate::values::{self, UtcOffset};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeSet;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write as _};
+#[cfg(all(feature = "std", feature = "flate2"))]
+use flate2::{write::GzEncoder, Compression};
+#[cfg(feature = "std")]
 use std::fs::File;
-use std::io::{self, Write};
+#[cfg(feature = "std")]
+use std::io::{self, BufReader, BufWriter, Read, Write};
+#[cfg(feature = "std")]
 use std::path::Path;
 
 /// The iCalendar object specified as `VCALENDAR` component
@@ -16,32 +39,140 @@ use std::path::Path;
 /// whole. (see [RFC5545 3.4 iCalendar Object](https://tools.ietf.org/html/rfc5545#section-3.4))
 /// The ICalendar struct can be thought of as the iCalendar object. This is
 /// where the specified components are added. To save the object as file, it
-/// needs to be written to a file.
+/// needs to be written to a file. The `Display` implementation produces the
+/// same output, so `to_string()` can be used to get the content in memory.
+/// Every content line ends with a CRLF, including the final `END:VCALENDAR`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ICalendar<'a>(Component<'a>);
+pub struct ICalendar<'a> {
+    pub(crate) properties: Vec<Property<'a>>,
+    pub(crate) components: Vec<CalendarComponent<'a>>,
+}
 
 impl<'a> ICalendar<'a> {
     /// Creates a new iCalendar object/`VCALENDAR` calendar component. The
-    /// `VERSION` and `PRODID` properties are required.
+    /// `VERSION` and `PRODID` properties are required. The version can be
+    /// given as string or as [`Version::v2_0()`].
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::Version;
+    /// use ics::ICalendar;
+    ///
+    /// let calendar = ICalendar::new(Version::v2_0(), "ics-rs");
+    /// assert_eq!(calendar, ICalendar::new("2.0", "ics-rs"));
+    /// ```
     pub fn new<V, P>(version: V, prodid: P) -> Self
     where
         V: Into<Cow<'a, str>>,
         P: Into<Cow<'a, str>>,
     {
-        ICalendar(Component {
-            name: "VCALENDAR".into(),
+        ICalendar {
             properties: vec![Version::new(version).into(), ProdID::new(prodid).into()],
-            subcomponents: Vec::new(),
-        })
+            components: Vec::new(),
+        }
     }
 
     /// Adds a property to the iCalendar object. Calendar properties are like
-    /// calendar attributes.
+    /// calendar attributes. RFC7986 adds calendar properties for subscribed
+    /// calendars like `NAME`, `DESCRIPTION`, `REFRESH-INTERVAL` and `SOURCE`.
+    /// They are written before the components regardless of the order in
+    /// which they are added.
     pub fn push<P>(&mut self, property: P)
     where
         P: Into<Property<'a>>,
     {
-        self.0.add_property(property);
+        self.properties.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the iCalendar object. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the iCalendar object. All properties with the same name are
+    /// replaced by it, or it is added if the iCalendar object does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the iCalendar object with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the iCalendar object with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the value of the `VERSION` property if the iCalendar object
+    /// has one.
+    pub fn version(&self) -> Option<&str> {
+        self.get("VERSION").map(Property::value)
+    }
+
+    /// Returns the value of the `PRODID` property if the iCalendar object has
+    /// one. It identifies the product that created the iCalendar object.
+    pub fn prod_id(&self) -> Option<&str> {
+        self.get("PRODID").map(Property::value)
+    }
+
+    /// Sets the `METHOD` property which is required for scheduling messages
+    /// of iTIP like invitations. An existing `METHOD` is replaced.
+    pub fn method(&mut self, method: Method<'a>) {
+        self.set(method);
+    }
+
+    /// Sets the `CALSCALE` property. An existing `CALSCALE` is replaced.
+    pub fn calscale<S>(&mut self, calscale: S)
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.set(CalScale::new(calscale));
+    }
+
+    /// Sets the name of the calendar that clients show for a subscribed
+    /// calendar. The text is escaped and written as `X-WR-CALNAME` and, with
+    /// the `rfc7986` feature, as `NAME`. Existing properties are replaced.
+    ///
+    /// `X-WR-CALNAME` is not standardized but read by Google Calendar, Apple
+    /// Calendar and Microsoft Outlook, while `NAME` is read by clients that
+    /// implement RFC7986. Writing both works with either kind of client.
+    pub fn display_name<S>(&mut self, name: S)
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let name = escape_text(name);
+        #[cfg(feature = "rfc7986")]
+        self.set(Name::new(name.clone()));
+        self.set(XWrCalName::new(name));
+    }
+
+    /// Sets the description of the calendar like
+    /// [`ICalendar::display_name()`]. The text is escaped and written as
+    /// `X-WR-CALDESC` and, with the `rfc7986` feature, as `DESCRIPTION`.
+    /// Existing properties are replaced.
+    ///
+    /// `X-WR-CALDESC` is read by Google Calendar and Apple Calendar, while
+    /// `DESCRIPTION` is read by clients that implement RFC7986.
+    pub fn description<S>(&mut self, description: S)
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let description = escape_text(description);
+        #[cfg(feature = "rfc7986")]
+        self.set(Description::new(description.clone()));
+        self.set(XWrCalDesc::new(description));
     }
 
     /// Adds a [`Component`] to the iCalendar object. This should be only used
@@ -50,258 +181,1352 @@ impl<'a> ICalendar<'a> {
     where
         C: Into<Component<'a>>,
     {
-        self.0.add_component(component);
+        self.components
+            .push(CalendarComponent::Other(component.into()));
     }
 
     /// Adds an `VEVENT` component to the iCalendar object.
     pub fn add_event(&mut self, event: Event<'a>) {
-        self.add_component(event);
+        self.components.push(CalendarComponent::Event(event));
     }
 
     /// Adds a `VTODO` component to the iCalendar object.
     pub fn add_todo(&mut self, todo: ToDo<'a>) {
-        self.add_component(todo);
+        self.components.push(CalendarComponent::ToDo(todo));
     }
 
     /// Adds a `VJOURNAL` component to the iCalendar object.
     pub fn add_journal(&mut self, journal: Journal<'a>) {
-        self.add_component(journal);
+        self.components.push(CalendarComponent::Journal(journal));
     }
 
     /// Adds a `VFREEBUSY` component to the iCalendar object.
     pub fn add_freebusy(&mut self, freebusy: FreeBusy<'a>) {
-        self.add_component(freebusy);
+        self.components.push(CalendarComponent::FreeBusy(freebusy));
     }
 
-    /// Adds a `VTIMEZONE` component to the iCalendar object.
+    /// Adds a `VTIMEZONE` component to the iCalendar object. The time zone is
+    /// skipped if there is already one with the same `TZID`, so it can be
+    /// added for every event that refers to it.
     pub fn add_timezone(&mut self, timezone: TimeZone<'a>) {
-        self.add_component(timezone);
+        let component = CalendarComponent::TimeZone(timezone);
+        if !self.contains_timezone(&component) {
+            self.components.push(component);
+        }
+    }
+
+    /// Returns an iterator over the `TZID` values of the `VTIMEZONE`
+    /// components of the iCalendar object.
+    pub fn timezone_ids(&self) -> impl Iterator<Item = &str> {
+        self.components.iter().filter_map(CalendarComponent::tzid)
+    }
+
+    /// Returns the `VTIMEZONE` component with the given `TZID`. The `TZID` is
+    /// case-insensitive.
+    pub fn timezone(&self, tzid: &str) -> Option<&TimeZone<'a>> {
+        self.components
+            .iter()
+            .find_map(|component| match component {
+                CalendarComponent::TimeZone(timezone)
+                    if component
+                        .tzid()
+                        .map_or(false, |id| id.eq_ignore_ascii_case(tzid)) =>
+                {
+                    Some(timezone)
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the values of the `TZID` parameters in the components that
+    /// have no matching `VTIMEZONE` component. Every time zone identifier
+    /// that a property refers to must be defined by a `VTIMEZONE`.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::parameters::TzIDParam;
+    /// use ics::properties::DtStart;
+    /// use ics::{Event, ICalendar};
+    ///
+    /// let mut dtstart = DtStart::new("19980119T020000");
+    /// dtstart.add(TzIDParam::new("America/New_York"));
+    /// let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    /// event.push(dtstart);
+    /// let mut calendar = ICalendar::new("2.0", "ics-rs");
+    /// calendar.add_event(event);
+    /// assert_eq!(calendar.missing_timezones(), ["America/New_York"]);
+    /// ```
+    pub fn missing_timezones(&self) -> Vec<&str> {
+        let mut references = Vec::new();
+        for component in &self.components {
+            component.tzid_references(&mut references);
+        }
+        let mut missing: Vec<&str> = Vec::new();
+        for tzid in references {
+            if self.timezone(tzid).is_none()
+                && !missing.iter().any(|id| id.eq_ignore_ascii_case(tzid))
+            {
+                missing.push(tzid);
+            }
+        }
+        missing
+    }
+
+    /// Adds a `VAVAILABILITY` component to the iCalendar object.
+    pub fn add_availability(&mut self, availability: Availability<'a>) {
+        self.components
+            .push(CalendarComponent::Availability(availability));
+    }
+
+    /// Adds a `VPOLL` component to the iCalendar object. `VPOLL` is
+    /// experimental, see [`Poll`].
+    pub fn add_poll(&mut self, poll: Poll<'a>) {
+        self.components.push(CalendarComponent::Poll(poll));
+    }
+
+    /// Moves all components of the other iCalendar object into this one. The
+    /// calendar properties of the other object like `VERSION` and `PRODID`
+    /// are dropped. A `VTIMEZONE` is only added if there is none with the same
+    /// `TZID` yet (see [`ICalendar::add_timezone()`]).
+    pub fn append(&mut self, other: ICalendar<'a>) {
+        for component in other.components {
+            if !self.contains_timezone(&component) {
+                self.components.push(component);
+            }
+        }
+    }
+
+    /// Moves the components of the other iCalendar object into this one like
+    /// [`ICalendar::append()`] but skips components with a `UID` that this
+    /// object already contains. The skipped components are returned so that
+    /// the caller can decide how to resolve the duplicates. Recurrence
+    /// instances with the same `UID` but a different `RECURRENCE-ID` are not
+    /// duplicates.
+    pub fn merge(&mut self, other: ICalendar<'a>) -> Vec<CalendarComponent<'a>> {
+        let mut duplicates = Vec::new();
+        for component in other.components {
+            if self.contains_timezone(&component) {
+                continue;
+            }
+            let instance = component.instance();
+            if instance.is_some() && self.components.iter().any(|c| c.instance() == instance) {
+                duplicates.push(component);
+            } else {
+                self.components.push(component);
+            }
+        }
+        duplicates
+    }
+
+    fn contains_timezone(&self, component: &CalendarComponent) -> bool {
+        match component.tzid() {
+            Some(tzid) => self.timezone_ids().any(|id| id.eq_ignore_ascii_case(tzid)),
+            None => false,
+        }
+    }
+
+    /// Returns an iterator over the components of the iCalendar object in the
+    /// order they were added.
+    pub fn components(&self) -> impl Iterator<Item = &CalendarComponent<'a>> {
+        self.components.iter()
+    }
+
+    /// Returns an iterator over the `VEVENT` components of the iCalendar
+    /// object.
+    pub fn events(&self) -> impl Iterator<Item = &Event<'a>> {
+        self.components
+            .iter()
+            .filter_map(|component| match component {
+                CalendarComponent::Event(event) => Some(event),
+                _ => None,
+            })
+    }
+
+    /// Returns an iterator that allows modifying the `VEVENT` components of
+    /// the iCalendar object.
+    pub fn events_mut(&mut self) -> impl Iterator<Item = &mut Event<'a>> {
+        self.components
+            .iter_mut()
+            .filter_map(|component| match component {
+                CalendarComponent::Event(event) => Some(event),
+                _ => None,
+            })
     }
 
     /// Generic convenience method to write the content of the iCalendar object
-    /// to a writer in the iCalendar format.
-    pub fn write<W>(&self, mut writer: W) -> io::Result<()>
+    /// to a writer in the iCalendar format. The content is written through a
+    /// buffer, so the writer does not need to be buffered.
+    #[cfg(feature = "std")]
+    pub fn write<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut writer = BufWriter::new(writer);
+        write!(writer, "{}", self)?;
+        writer.flush()
+    }
+
+    /// Writes the content of the iCalendar object like [`ICalendar::write()`]
+    /// but with the given [`WriteOptions`].
+    ///
+    /// # Example
+    /// ```
+    /// use ics::{ICalendar, WriteOptions};
+    ///
+    /// let calendar = ICalendar::new("2.0", "ics-rs");
+    /// let options = WriteOptions::default().fold_width(76);
+    /// let mut output = Vec::new();
+    /// calendar.write_with_options(&mut output, &options).unwrap();
+    /// assert_eq!(output, calendar.to_vec());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_with_options<W>(&self, writer: W, options: &WriteOptions) -> io::Result<()>
     where
         W: Write,
     {
-        write!(writer, "{}", self)
+        if *options == WriteOptions::default() {
+            return self.write(writer);
+        }
+        let mut writer = IoWriter {
+            writer: BufWriter::new(writer),
+            error: None,
+        };
+        let trailing_line_break = options.trailing_line_break;
+        let result = if options.fold_width == contentline::LIMIT {
+            self.format(&mut writer, trailing_line_break)
+        } else {
+            let mut refold = contentline::Refold::new(&mut writer, options.fold_width);
+            self.format(&mut refold, trailing_line_break)
+                .and_then(|_| refold.finish().map(|_| ()))
+        };
+        match (result, writer.error) {
+            (Err(_), Some(error)) => Err(error),
+            (Err(_), None) => Err(io::Error::new(io::ErrorKind::Other, "formatter error")),
+            (Ok(()), _) => writer.writer.flush(),
+        }
+    }
+
+    /// Returns the content of the iCalendar object in the iCalendar format as
+    /// bytes like [`ICalendar::write()`] into a `Vec`.
+    #[cfg(feature = "std")]
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        self.write(&mut output)
+            .expect("writing to a Vec never fails");
+        output
     }
 
     /// Creates a file from the path and saves the content of the iCalendar
     /// object in the iCalendar format.
+    #[cfg(feature = "std")]
     pub fn save_file<P>(&self, filename: P) -> io::Result<()>
     where
         P: AsRef<Path>,
     {
         self.write(File::create(filename)?)
     }
-}
 
-impl<'a> fmt::Display for ICalendar<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+    /// Saves the iCalendar object like [`ICalendar::save_file()`] but starts
+    /// the file with the UTF-8 byte order mark. RFC5545 does not use a byte
+    /// order mark, so this is only meant for consumers that guess the encoding
+    /// otherwise, e.g. some import paths of Microsoft Outlook.
+    #[cfg(feature = "std")]
+    pub fn save_file_with_bom<P>(&self, filename: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::create(filename)?;
+        file.write_all("\u{feff}".as_bytes())?;
+        self.write(file)
     }
-}
 
-impl<'a> From<ICalendar<'a>> for Component<'a> {
-    fn from(component: ICalendar<'a>) -> Self {
-        component.0
+    /// Writes the content of the iCalendar object like [`ICalendar::write()`]
+    /// but compressed with gzip, e.g. for a subscription feed that is served
+    /// with `Content-Encoding: gzip`.
+    #[cfg(all(feature = "std", feature = "flate2"))]
+    pub fn write_gz<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut encoder = GzEncoder::new(writer, Compression::default());
+        self.write(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
     }
-}
-
-/// The `VEVENT` calendar component
-///
-/// An [`Event`] component is a grouping of component properties, possibly
-/// including an [`Alarm`], that represents a scheduled amount of time on a
-/// calendar. (see [RFC5545 3.6.1. Event Component](https://tools.ietf.org/html/rfc5545#section-3.6.1))
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Event<'a> {
-    properties: Vec<Property<'a>>,
-    alarms: Vec<Alarm<'a>>,
-}
 
-impl<'a> Event<'a> {
-    /// Creates a new `VEVENT` calendar component. The `UID` and `DTSTAMP`
-    /// properties are required. A `UID` should be generated randomly for
-    /// security reasons.
-    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    /// Creates a file from the path and saves the content of the iCalendar
+    /// object compressed with gzip like [`ICalendar::write_gz()`]. The path
+    /// usually ends with `.ics.gz`.
+    #[cfg(all(feature = "std", feature = "flate2"))]
+    pub fn save_file_gz<P>(&self, filename: P) -> io::Result<()>
     where
-        U: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
+        P: AsRef<Path>,
     {
-        Self {
-            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
-            alarms: Vec::new(),
-        }
+        self.write_gz(File::create(filename)?)
     }
 
-    /// Adds a property to the event. RFC5545 and RFC7986 specify which
-    /// properties can be added to an event.
-    pub fn push<P: Into<Property<'a>>>(&mut self, property: P) {
-        self.properties.push(property.into());
+    /// Returns the iCalendar object with the properties of every component in
+    /// a canonical order. The properties that the component requires like
+    /// `UID` and `DTSTAMP` come first and the others are sorted by name.
+    ///
+    /// The order of properties has no meaning in iCalendar, so this is only
+    /// useful to get the same output for calendars that were built in a
+    /// different order, e.g. for hashing or comparing files. The order of the
+    /// components is kept.
+    pub fn sorted(mut self) -> Self {
+        sort_properties(&mut self.properties, &["VERSION", "PRODID"]);
+        for component in &mut self.components {
+            match component {
+                CalendarComponent::Event(c) => c.sort(),
+                CalendarComponent::ToDo(c) => {
+                    sort_properties(&mut c.properties, COMPONENT_PROPERTIES);
+                    c.alarms.iter_mut().for_each(Alarm::sort);
+                }
+                CalendarComponent::Journal(c) => sort_properties(&mut c.0, COMPONENT_PROPERTIES),
+                CalendarComponent::FreeBusy(c) => sort_properties(&mut c.0, COMPONENT_PROPERTIES),
+                CalendarComponent::TimeZone(c) => {
+                    sort_properties(&mut c.properties, &["TZID"]);
+                    for zone_time in &mut c.zone_times {
+                        match zone_time {
+                            ZoneTime::Standard(p) => {
+                                sort_properties(&mut p.0, ZONE_TIME_PROPERTIES)
+                            }
+                            ZoneTime::Daylight(p) => {
+                                sort_properties(&mut p.0, ZONE_TIME_PROPERTIES)
+                            }
+                        }
+                    }
+                }
+                CalendarComponent::Availability(c) => {
+                    sort_properties(&mut c.properties, COMPONENT_PROPERTIES);
+                    for available in &mut c.available {
+                        sort_properties(&mut available.0, COMPONENT_PROPERTIES);
+                    }
+                }
+                CalendarComponent::Poll(c) => {
+                    sort_properties(&mut c.properties, COMPONENT_PROPERTIES);
+                    c.candidates.iter_mut().for_each(Event::sort);
+                }
+                CalendarComponent::Other(c) => sort_component(c),
+            }
+        }
+        self
     }
 
-    /// Adds a `VALARM` to the event.
-    pub fn add_alarm(&mut self, alarm: Alarm<'a>) {
-        self.alarms.push(alarm);
+    /// Returns the length in bytes of the iCalendar object in the iCalendar
+    /// format, e.g. for the `Content-Length` header. It is the exact length
+    /// of the output of [`ICalendar::write()`] and `to_string()`, but the
+    /// content is only counted and not kept in memory.
+    pub fn serialized_len(&self) -> usize {
+        let mut length = contentline::ByteLength::default();
+        write!(length, "{}", self).expect("counting the length never fails");
+        length.0
     }
-}
 
-impl<'a> fmt::Display for Event<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VEVENT\r")?;
-        for property in &self.properties {
-            write!(f, "{}", property)?;
-        }
-        for component in &self.alarms {
-            write!(f, "{}", component)?;
-        }
-        writeln!(f, "END:VEVENT\r")
+    /// Returns the length in bytes of the longest content line before folding,
+    /// including `BEGIN` and `END` lines. Lines are folded after 75 bytes, but
+    /// some consumers fail on long content lines anyway.
+    pub fn max_unfolded_length(&self) -> usize {
+        let mut length = contentline::UnfoldedLength::default();
+        write!(length, "{}", self).expect("measuring the length never fails");
+        length.longest()
     }
-}
 
-impl<'a> From<Event<'a>> for Component<'a> {
-    fn from(component: Event<'a>) -> Self {
-        Component {
-            name: "VEVENT".into(),
-            properties: component.properties,
-            subcomponents: component.alarms.into_iter().map(Component::from).collect(),
+    /// Checks that the iCalendar object and its components contain the
+    /// properties and sub-components which the specification requires.
+    /// Components with the same `UID` must have different `RECURRENCE-ID`
+    /// properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VCALENDAR", &self.properties)?;
+        validation::require("VCALENDAR", &self.properties, &["VERSION", "PRODID"])?;
+        let mut instances = BTreeSet::new();
+        for component in &self.components {
+            if let Some(instance) = component.instance() {
+                if !instances.insert(instance) {
+                    return Err(ValidationError::DuplicateUID {
+                        uid: instance.0.to_string(),
+                    });
+                }
+            }
+            match component {
+                CalendarComponent::Event(c) => c.validate()?,
+                CalendarComponent::ToDo(c) => c.validate()?,
+                CalendarComponent::Journal(c) => c.validate()?,
+                CalendarComponent::FreeBusy(c) => c.validate()?,
+                CalendarComponent::TimeZone(c) => c.validate()?,
+                CalendarComponent::Availability(c) => c.validate()?,
+                CalendarComponent::Poll(c) => c.validate()?,
+                CalendarComponent::Other(_) => {}
+            }
         }
+        Ok(())
     }
-}
-
-/// The `VTODO` calendar component
-///
-/// A [`ToDo`] component is a grouping of component properties, possibly
-/// including an [`Alarm`], that represent an action-item or assignment. (see [RFC5545 3.6.2. To-Do Component](https://tools.ietf.org/html/rfc5545#section-3.6.2))
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct ToDo<'a> {
-    properties: Vec<Property<'a>>,
-    alarms: Vec<Alarm<'a>>,
-}
 
-impl<'a> ToDo<'a> {
-    /// Creates a new `VTODO` calendar component. The `UID` and `DTSTAMP`
-    /// properties are required. A `UID` should be generated randomly for
-    /// security reasons.
-    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    /// Validates the iCalendar object before saving it like
+    /// [`ICalendar::save_file()`]. The error is either an [`Error::Validation`]
+    /// or an [`Error::Io`].
+    #[cfg(feature = "std")]
+    pub fn save_file_validated<P>(&self, filename: P) -> Result<(), Error>
     where
-        U: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
+        P: AsRef<Path>,
     {
-        Self {
-            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
-            alarms: Vec::new(),
-        }
+        self.validate()?;
+        self.save_file(filename)?;
+        Ok(())
+    }
+}
+
+impl ICalendar<'static> {
+    /// Parses an iCalendar object from its text representation. Folded lines
+    /// are unfolded and values are kept as they are, so text values still
+    /// need to be unescaped. Unknown components and properties are preserved.
+    /// A byte order mark at the beginning is skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::ICalendar;
+    ///
+    /// let input = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:ics-rs\r\nEND:VCALENDAR\r\n";
+    /// let calendar = ICalendar::parse(input).unwrap();
+    /// assert_eq!(calendar, ICalendar::new("2.0", "ics-rs"));
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        parser::read_calendar(input.as_bytes())
     }
 
-    /// Adds a property to the to-do. RFC5545 and RFC7986 specify which
-    /// properties can be added to a to-do.
-    pub fn push<P>(&mut self, property: P)
+    /// Reads and parses an iCalendar object from a reader like a file. See
+    /// [`ICalendar::parse()`] for more information.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R>(reader: R) -> Result<Self, ParseError>
     where
-        P: Into<Property<'a>>,
+        R: Read,
     {
-        self.properties.push(property.into());
-    }
-
-    /// Adds an `VALARM` to the to-do.
-    pub fn add_alarm(&mut self, alarm: Alarm<'a>) {
-        self.alarms.push(alarm);
+        parser::read_calendar(BufReader::new(reader))
     }
 }
 
-impl<'a> fmt::Display for ToDo<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VTODO\r")?;
+impl<'a> ICalendar<'a> {
+    fn format<W: fmt::Write>(&self, writer: &mut W, trailing_line_break: bool) -> fmt::Result {
+        writeln!(writer, "BEGIN:VCALENDAR\r")?;
         for property in &self.properties {
-            write!(f, "{}", property)?;
+            write!(writer, "{}", property)?;
         }
-        for component in &self.alarms {
-            write!(f, "{}", component)?;
+        for component in &self.components {
+            write!(writer, "{}", component)?;
         }
-        writeln!(f, "END:VTODO\r")
+        writer.write_str("END:VCALENDAR")?;
+        if trailing_line_break {
+            writer.write_str("\r\n")?;
+        }
+        Ok(())
     }
 }
 
-impl<'a> From<ToDo<'a>> for Component<'a> {
-    fn from(component: ToDo<'a>) -> Self {
+impl<'a> fmt::Display for ICalendar<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.format(f, true)
+    }
+}
+
+impl<'a> From<ICalendar<'a>> for Component<'a> {
+    fn from(component: ICalendar<'a>) -> Self {
         Component {
-            name: "VTODO".into(),
+            name: "VCALENDAR".into(),
             properties: component.properties,
-            subcomponents: component.alarms.into_iter().map(Component::from).collect(),
+            subcomponents: component
+                .components
+                .into_iter()
+                .map(Component::from)
+                .collect(),
         }
     }
 }
 
-/// The `VJOURNAL` calendar component
+/// Options for writing an [`ICalendar`] object with
+/// [`ICalendar::write_with_options()`]
 ///
-/// A [`Journal`] component is a grouping of component properties that represent
-/// one or more descriptive text notes associated with a particular calendar
-/// date. (see [RFC5545 3.6.3. Journal Component](https://tools.ietf.org/html/rfc5545#section-3.6.3))
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Journal<'a>(Vec<Property<'a>>);
+/// The default options write the same output as [`ICalendar::write()`].
+/// Options are changed with the builder methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct WriteOptions {
+    pub(crate) fold_width: usize,
+    pub(crate) trailing_line_break: bool,
+}
 
-impl<'a> Journal<'a> {
-    /// Creates a new `VJOURNAL` calendar component. The `UID` and `DTSTAMP`
-    /// properties are required. A `UID` should be generated randomly for
-    /// security reasons.
-    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
-    where
-        U: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
-    {
-        Journal(vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()])
+impl WriteOptions {
+    /// Sets the maximum length of a line in bytes without the CRLF. RFC5545
+    /// folds lines after 75 bytes which is the default. Some legacy consumers
+    /// expect lines of 76 bytes instead. Widths below 3 bytes are raised to 3
+    /// like in [`fold_with_width`](crate::contentline::fold_with_width).
+    pub fn fold_width(mut self, width: usize) -> Self {
+        self.fold_width = width;
+        self
     }
 
-    /// Adds a property to the journal. RFC5545 and RFC7986 specify which
-    /// properties can be added to a journal.
-    pub fn push<P>(&mut self, property: P)
-    where
-        P: Into<Property<'a>>,
-    {
-        self.0.push(property.into());
+    /// Sets whether the CRLF after `END:VCALENDAR` is written, which it is by
+    /// default.
+    ///
+    /// Every content line ends with a CRLF including the last one, which is
+    /// what RFC5545 specifies. Omitting it is only meant for consumers that
+    /// fail on the line break at the end of the file.
+    pub fn trailing_line_break(mut self, enabled: bool) -> Self {
+        self.trailing_line_break = enabled;
+        self
     }
 }
 
-impl<'a> fmt::Display for Journal<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VJOURNAL\r")?;
-        for property in &self.0 {
-            write!(f, "{}", property)?;
+impl Default for WriteOptions {
+    fn default() -> Self {
+        WriteOptions {
+            fold_width: contentline::LIMIT,
+            trailing_line_break: true,
         }
-        writeln!(f, "END:VJOURNAL\r")
     }
 }
 
-impl<'a> From<Journal<'a>> for Component<'a> {
-    fn from(component: Journal<'a>) -> Self {
-        Component {
-            name: "VJOURNAL".into(),
-            properties: component.0,
-            subcomponents: Vec::new(),
-        }
+// Writes formatted text to an `io::Write` and keeps the I/O error that a
+// `fmt::Error` cannot carry.
+#[cfg(feature = "std")]
+struct IoWriter<W: Write> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
     }
 }
 
-/// The `VFREEBUSY` calendar component
+/// Builder for an [`ICalendar`] object
 ///
-///  A [`FreeBusy`] component is a grouping of component properties that
-/// represents either a request for free or busy time information, a reply to a
-/// request for free or busy time information, or a published set of busy time
-/// information. (see [RFC5545 3.6.4. Free/Busy Component Component](https://tools.ietf.org/html/rfc5545#section-3.6.4))
+/// The builder sets the calendar properties in one expression. `VERSION` is
+/// `2.0` unless it is changed. Components can still be added to the built
+/// object.
+///
+/// # Example
+/// ```
+/// use ics::properties::Method;
+/// use ics::ICalendarBuilder;
+///
+/// let calendar = ICalendarBuilder::new("-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN")
+///     .calscale("GREGORIAN")
+///     .method(Method::publish())
+///     .name("Conferences")
+///     .build();
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct FreeBusy<'a>(Vec<Property<'a>>);
+pub struct ICalendarBuilder<'a> {
+    calendar: ICalendar<'a>,
+}
 
-impl<'a> FreeBusy<'a> {
-    /// Creates a new `VFREEBUSY` calendar component. The `UID` and `DTSTAMP`
-    /// properties are required. A `UID` should be generated randomly for
-    /// security reasons.
-    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+impl<'a> ICalendarBuilder<'a> {
+    /// Creates a new builder for an iCalendar object with the `PRODID`
+    /// property.
+    pub fn new<P>(prodid: P) -> Self
     where
-        U: Into<Cow<'a, str>>,
-        D: Into<Cow<'a, str>>,
+        P: Into<Cow<'a, str>>,
     {
-        FreeBusy(vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()])
+        ICalendarBuilder {
+            calendar: ICalendar::new(Version::v2_0(), prodid),
+        }
+    }
+
+    /// Sets the `VERSION` property.
+    pub fn version<V>(mut self, version: V) -> Self
+    where
+        V: Into<Cow<'a, str>>,
+    {
+        self.calendar.set(Version::new(version));
+        self
+    }
+
+    /// Sets the `PRODID` property.
+    pub fn prod_id<P>(mut self, prodid: P) -> Self
+    where
+        P: Into<Cow<'a, str>>,
+    {
+        self.calendar.set(ProdID::new(prodid));
+        self
+    }
+
+    /// Sets the `METHOD` property.
+    pub fn method(mut self, method: Method<'a>) -> Self {
+        self.calendar.method(method);
+        self
+    }
+
+    /// Sets the `CALSCALE` property.
+    pub fn calscale<S>(mut self, calscale: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.calendar.calscale(calscale);
+        self
+    }
+
+    /// Sets the `X-WR-CALNAME` property which many clients show as the name of
+    /// the calendar.
+    pub fn name<S>(mut self, name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.calendar.set(XWrCalName::new(name));
+        self
+    }
+
+    /// Adds a `VTIMEZONE` component, see [`ICalendar::add_timezone()`].
+    pub fn timezone(mut self, timezone: TimeZone<'a>) -> Self {
+        self.calendar.add_timezone(timezone);
+        self
+    }
+
+    /// Creates the iCalendar object.
+    pub fn build(self) -> ICalendar<'a> {
+        self.calendar
+    }
+}
+
+/// A component of an [`ICalendar`] object
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CalendarComponent<'a> {
+    /// `VEVENT` component
+    Event(Event<'a>),
+    /// `VTODO` component
+    ToDo(ToDo<'a>),
+    /// `VJOURNAL` component
+    Journal(Journal<'a>),
+    /// `VFREEBUSY` component
+    FreeBusy(FreeBusy<'a>),
+    /// `VTIMEZONE` component
+    TimeZone(TimeZone<'a>),
+    /// `VAVAILABILITY` component
+    Availability(Availability<'a>),
+    /// `VPOLL` component (experimental)
+    Poll(Poll<'a>),
+    /// IANA/non-standard component
+    Other(Component<'a>),
+}
+
+impl<'a> CalendarComponent<'a> {
+    /// Returns the value of the `UID` property if the component has one.
+    pub fn uid(&self) -> Option<&str> {
+        validation::find(self.properties(), "UID").map(|uid| uid.value.as_ref())
+    }
+
+    fn properties(&self) -> &[Property<'a>] {
+        match self {
+            CalendarComponent::Event(c) => &c.properties,
+            CalendarComponent::ToDo(c) => &c.properties,
+            CalendarComponent::Journal(c) => &c.0,
+            CalendarComponent::FreeBusy(c) => &c.0,
+            CalendarComponent::TimeZone(c) => &c.properties,
+            CalendarComponent::Availability(c) => &c.properties,
+            CalendarComponent::Poll(c) => &c.properties,
+            CalendarComponent::Other(c) => &c.properties,
+        }
+    }
+
+    // The UID with the RECURRENCE-ID identifies an instance of a component.
+    pub(crate) fn instance(&self) -> Option<(&str, Option<&str>)> {
+        let recurrence_id = validation::find(self.properties(), "RECURRENCE-ID");
+        self.uid()
+            .map(|uid| (uid, recurrence_id.map(|id| id.value.as_ref())))
+    }
+
+    // Collects the values of the TZID parameters of all properties.
+    fn tzid_references<'b>(&'b self, references: &mut Vec<&'b str>) {
+        match self {
+            CalendarComponent::Event(c) => c.tzid_references(references),
+            CalendarComponent::ToDo(c) => {
+                tzid_parameters(&c.properties, references);
+                for alarm in &c.alarms {
+                    tzid_parameters(&alarm.0, references);
+                }
+            }
+            CalendarComponent::Journal(c) => tzid_parameters(&c.0, references),
+            CalendarComponent::FreeBusy(c) => tzid_parameters(&c.0, references),
+            // A time zone defines its TZID and does not refer to one.
+            CalendarComponent::TimeZone(_) => {}
+            CalendarComponent::Availability(c) => {
+                tzid_parameters(&c.properties, references);
+                for available in &c.available {
+                    tzid_parameters(&available.0, references);
+                }
+            }
+            CalendarComponent::Poll(c) => {
+                tzid_parameters(&c.properties, references);
+                for candidate in &c.candidates {
+                    candidate.tzid_references(references);
+                }
+            }
+            CalendarComponent::Other(c) => component_tzid_parameters(c, references),
+        }
+    }
+
+    fn tzid(&self) -> Option<&str> {
+        match self {
+            CalendarComponent::TimeZone(c) => {
+                validation::find(&c.properties, "TZID").map(|tzid| tzid.value.as_ref())
+            }
+            _ => None,
+        }
+    }
+
+    // Converts a generic component into the matching calendar component.
+    // Components with sub-components that the specific type cannot represent
+    // stay generic, so nothing is lost.
+    pub(crate) fn from_component(component: Component<'a>) -> Self {
+        let is = |name: &str| component.name.eq_ignore_ascii_case(name);
+        let has_only = |names: &[&str]| {
+            component.subcomponents.iter().all(|c| {
+                c.subcomponents.is_empty() && names.iter().any(|n| c.name.eq_ignore_ascii_case(n))
+            })
+        };
+
+        if Event::is_event(&component) {
+            CalendarComponent::Event(Event::from_component(component))
+        } else if is("VTODO") && has_only(&["VALARM"]) {
+            let alarms = component
+                .subcomponents
+                .into_iter()
+                .map(|c| Alarm(c.properties))
+                .collect();
+            CalendarComponent::ToDo(ToDo {
+                properties: component.properties,
+                alarms,
+            })
+        } else if is("VJOURNAL") && has_only(&[]) {
+            CalendarComponent::Journal(Journal(component.properties))
+        } else if is("VFREEBUSY") && has_only(&[]) {
+            CalendarComponent::FreeBusy(FreeBusy(component.properties))
+        } else if is("VTIMEZONE") && has_only(&["STANDARD", "DAYLIGHT"]) {
+            let zone_times = component
+                .subcomponents
+                .into_iter()
+                .map(|c| {
+                    if c.name.eq_ignore_ascii_case("STANDARD") {
+                        ZoneTime::Standard(Standard(c.properties))
+                    } else {
+                        ZoneTime::Daylight(Daylight(c.properties))
+                    }
+                })
+                .collect();
+            CalendarComponent::TimeZone(TimeZone {
+                properties: component.properties,
+                zone_times,
+            })
+        } else if is("VAVAILABILITY") && has_only(&["AVAILABLE"]) {
+            let available = component
+                .subcomponents
+                .into_iter()
+                .map(|c| Available(c.properties))
+                .collect();
+            CalendarComponent::Availability(Availability {
+                properties: component.properties,
+                available,
+            })
+        } else if is("VPOLL") && component.subcomponents.iter().all(Event::is_event) {
+            CalendarComponent::Poll(Poll {
+                properties: component.properties,
+                candidates: component
+                    .subcomponents
+                    .into_iter()
+                    .map(Event::from_component)
+                    .collect(),
+            })
+        } else {
+            CalendarComponent::Other(component)
+        }
+    }
+}
+
+impl<'a> fmt::Display for CalendarComponent<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalendarComponent::Event(c) => write!(f, "{}", c),
+            CalendarComponent::ToDo(c) => write!(f, "{}", c),
+            CalendarComponent::Journal(c) => write!(f, "{}", c),
+            CalendarComponent::FreeBusy(c) => write!(f, "{}", c),
+            CalendarComponent::TimeZone(c) => write!(f, "{}", c),
+            CalendarComponent::Availability(c) => write!(f, "{}", c),
+            CalendarComponent::Poll(c) => write!(f, "{}", c),
+            CalendarComponent::Other(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+impl<'a> From<CalendarComponent<'a>> for Component<'a> {
+    fn from(component: CalendarComponent<'a>) -> Self {
+        match component {
+            CalendarComponent::Event(c) => Self::from(c),
+            CalendarComponent::ToDo(c) => Self::from(c),
+            CalendarComponent::Journal(c) => Self::from(c),
+            CalendarComponent::FreeBusy(c) => Self::from(c),
+            CalendarComponent::TimeZone(c) => Self::from(c),
+            CalendarComponent::Availability(c) => Self::from(c),
+            CalendarComponent::Poll(c) => Self::from(c),
+            CalendarComponent::Other(c) => c,
+        }
+    }
+}
+
+/// The `VEVENT` calendar component
+///
+/// An [`Event`] component is a grouping of component properties, possibly
+/// including an [`Alarm`], a [`Participant`] or a [`StructuredLocation`], that
+/// represents a scheduled amount of time on a calendar. (see [RFC5545 3.6.1. Event Component](https://tools.ietf.org/html/rfc5545#section-3.6.1))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Event<'a> {
+    pub(crate) properties: Vec<Property<'a>>,
+    alarms: Vec<Alarm<'a>>,
+    participants: Vec<Participant<'a>>,
+    locations: Vec<StructuredLocation<'a>>,
+}
+
+impl<'a> Event<'a> {
+    /// Creates a new `VEVENT` calendar component. The `UID` and `DTSTAMP`
+    /// properties are required. A `UID` should be generated randomly for
+    /// security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Self {
+            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
+            alarms: Vec::new(),
+            participants: Vec::new(),
+            locations: Vec::new(),
+        }
+    }
+
+    /// Creates a new `VEVENT` calendar component with the `DTSTAMP` property
+    /// set to the current time in UTC. The time is taken from the system clock,
+    /// so the output is not deterministic and [`Event::new()`] should be
+    /// preferred in tests.
+    #[cfg(feature = "std")]
+    pub fn now<U>(uid: U) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+    {
+        Self::new(uid, DateTime::now().to_string())
+    }
+
+    /// Creates a new `VEVENT` calendar component with a random `UID` (see
+    /// [`UID::random()`]). The `DTSTAMP` property is required.
+    #[cfg(feature = "uuid")]
+    pub fn with_random_uid<D>(dtstamp: D) -> Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        Self::new(random_uid(), dtstamp)
+    }
+
+    /// Adds a property to the event. RFC5545 and RFC7986 specify which
+    /// properties can be added to an event.
+    pub fn push<P: Into<Property<'a>>>(&mut self, property: P) {
+        self.properties.push(property.into());
+    }
+
+    /// Adds a property to the event and returns the event, so that calls can
+    /// be chained.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::{DtStart, Summary};
+    /// use ics::Event;
+    ///
+    /// let event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z")
+    ///     .with(Summary::new("Networld+Interop Conference"))
+    ///     .with(DtStart::new("19960918T143000Z"));
+    /// ```
+    pub fn with<P: Into<Property<'a>>>(mut self, property: P) -> Self {
+        self.push(property);
+        self
+    }
+
+    /// Removes all properties with the given name from the event. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the event. All properties with the same name are
+    /// replaced by it, or it is added if the event does not contain one yet.
+    ///
+    /// This is meant for properties that may occur at most once in an event:
+    /// `CLASS`, `CREATED`, `DESCRIPTION`, `DTEND`, `DTSTAMP`, `DTSTART`,
+    /// `DURATION`, `GEO`, `LAST-MODIFIED`, `LOCATION`, `ORGANIZER`, `PRIORITY`,
+    /// `RECURRENCE-ID`, `RRULE`, `SEQUENCE`, `STATUS`, `SUMMARY`, `TRANSP`,
+    /// `UID` and `URL`. The properties `ATTACH`, `ATTENDEE`, `CATEGORIES`,
+    /// `COMMENT`, `CONTACT`, `EXDATE`, `RDATE`, `RELATED-TO`, `RESOURCES` and
+    /// `REQUEST-STATUS` may occur several times and should be added with
+    /// [`Event::push()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the event with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the event with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds a `VALARM` to the event.
+    pub fn add_alarm(&mut self, alarm: Alarm<'a>) {
+        self.alarms.push(alarm);
+    }
+
+    /// Adds a `VALARM` that displays the description the given duration
+    /// before the start of the event. The text of the description is escaped.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::values::Duration;
+    /// use ics::Event;
+    ///
+    /// let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    /// event.add_alarm_before(Duration::new().minutes(15), "Conference starts soon");
+    /// ```
+    pub fn add_alarm_before<S>(&mut self, duration: values::Duration, description: S)
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let trigger = Trigger::relative(duration.negative(), Related::Start);
+        self.add_alarm(Alarm::display(trigger, Description::text(description)));
+    }
+
+    /// Adds a `VPARTICIPANT` to the event.
+    pub fn add_participant(&mut self, participant: Participant<'a>) {
+        self.participants.push(participant);
+    }
+
+    /// Adds a `VLOCATION` to the event. Unlike the `LOCATION` property, it
+    /// can describe the location with properties like `NAME`, `GEO` and
+    /// `URL`.
+    pub fn add_location(&mut self, location: StructuredLocation<'a>) {
+        self.locations.push(location);
+    }
+
+    // Checks if a generic component can be converted into an event.
+    fn is_event(component: &Component) -> bool {
+        component.name.eq_ignore_ascii_case("VEVENT")
+            && component.subcomponents.iter().all(|c| {
+                Participant::is_participant(c)
+                    || c.subcomponents.is_empty()
+                        && (c.name.eq_ignore_ascii_case("VALARM")
+                            || c.name.eq_ignore_ascii_case("VLOCATION"))
+            })
+    }
+
+    fn from_component(component: Component<'a>) -> Self {
+        let mut event = Event {
+            properties: component.properties,
+            alarms: Vec::new(),
+            participants: Vec::new(),
+            locations: Vec::new(),
+        };
+        for c in component.subcomponents {
+            if c.name.eq_ignore_ascii_case("VALARM") {
+                event.alarms.push(Alarm(c.properties));
+            } else if c.name.eq_ignore_ascii_case("VLOCATION") {
+                event.locations.push(StructuredLocation(c.properties));
+            } else {
+                event.participants.push(Participant::from_component(c));
+            }
+        }
+        event
+    }
+
+    fn sort(&mut self) {
+        sort_properties(&mut self.properties, COMPONENT_PROPERTIES);
+        self.alarms.iter_mut().for_each(Alarm::sort);
+        self.participants.iter_mut().for_each(Participant::sort);
+        for location in &mut self.locations {
+            sort_properties(&mut location.0, &["UID"]);
+        }
+    }
+
+    fn tzid_references<'b>(&'b self, references: &mut Vec<&'b str>) {
+        tzid_parameters(&self.properties, references);
+        for alarm in &self.alarms {
+            tzid_parameters(&alarm.0, references);
+        }
+        for participant in &self.participants {
+            tzid_parameters(&participant.properties, references);
+            for location in &participant.locations {
+                tzid_parameters(&location.0, references);
+            }
+        }
+        for location in &self.locations {
+            tzid_parameters(&location.0, references);
+        }
+    }
+
+    /// Checks that the event, its alarms, participants and locations contain
+    /// the required properties. The event must not contain both `DTEND` and
+    /// `DURATION`, and `DTEND` must have the same value type as `DTSTART`.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VEVENT", &self.properties)?;
+        validation::require("VEVENT", &self.properties, &["UID", "DTSTAMP"])?;
+        validation::check_date_lists("VEVENT", &self.properties)?;
+        validation::check_end("VEVENT", &self.properties, "DTEND")?;
+        self.alarms.iter().try_for_each(Alarm::validate)?;
+        self.participants
+            .iter()
+            .try_for_each(Participant::validate)?;
+        self.locations
+            .iter()
+            .try_for_each(StructuredLocation::validate)
+    }
+}
+
+impl<'a> fmt::Display for Event<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VEVENT\r")?;
+        for property in &self.properties {
+            write!(f, "{}", property)?;
+        }
+        for component in &self.alarms {
+            write!(f, "{}", component)?;
+        }
+        for component in &self.participants {
+            write!(f, "{}", component)?;
+        }
+        for component in &self.locations {
+            write!(f, "{}", component)?;
+        }
+        writeln!(f, "END:VEVENT\r")
+    }
+}
+
+impl<'a> From<Event<'a>> for Component<'a> {
+    fn from(component: Event<'a>) -> Self {
+        let alarms = component.alarms.into_iter().map(Component::from);
+        let participants = component.participants.into_iter().map(Component::from);
+        let locations = component.locations.into_iter().map(Component::from);
+        Component {
+            name: "VEVENT".into(),
+            properties: component.properties,
+            subcomponents: alarms.chain(participants).chain(locations).collect(),
+        }
+    }
+}
+
+/// The `VTODO` calendar component
+///
+/// A [`ToDo`] component is a grouping of component properties, possibly
+/// including an [`Alarm`], that represent an action-item or assignment. (see [RFC5545 3.6.2. To-Do Component](https://tools.ietf.org/html/rfc5545#section-3.6.2))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ToDo<'a> {
+    properties: Vec<Property<'a>>,
+    alarms: Vec<Alarm<'a>>,
+}
+
+impl<'a> ToDo<'a> {
+    /// Creates a new `VTODO` calendar component. The `UID` and `DTSTAMP`
+    /// properties are required. A `UID` should be generated randomly for
+    /// security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Self {
+            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
+            alarms: Vec::new(),
+        }
+    }
+
+    /// Creates a new `VTODO` calendar component with a random `UID` (see
+    /// [`UID::random()`]). The `DTSTAMP` property is required.
+    #[cfg(feature = "uuid")]
+    pub fn with_random_uid<D>(dtstamp: D) -> Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        Self::new(random_uid(), dtstamp)
+    }
+
+    /// Adds a property to the to-do. RFC5545 and RFC7986 specify which
+    /// properties can be added to a to-do.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.properties.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the to-do. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the to-do. All properties with the same name are
+    /// replaced by it, or it is added if the to-do does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the to-do with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the to-do with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds an `VALARM` to the to-do.
+    pub fn add_alarm(&mut self, alarm: Alarm<'a>) {
+        self.alarms.push(alarm);
+    }
+
+    /// Checks that the to-do and its alarms contain the required properties.
+    /// The `PERCENT-COMPLETE` property must be between 0 and 100 and a
+    /// completed to-do must be 100 percent complete and contain `COMPLETED`.
+    /// The to-do must not contain both `DUE` and `DURATION`, and `DUE` must
+    /// have the same value type as `DTSTART` and must not precede it.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VTODO", &self.properties)?;
+        validation::require("VTODO", &self.properties, &["UID", "DTSTAMP"])?;
+        validation::check_date_lists("VTODO", &self.properties)?;
+        let completed = validation::find(&self.properties, "STATUS").map_or(false, |status| {
+            status.value.eq_ignore_ascii_case("COMPLETED")
+        });
+        if let Some(percent) = validation::find(&self.properties, "PERCENT-COMPLETE") {
+            let percent = match percent.value.parse::<u8>() {
+                Ok(percent) if percent <= 100 => percent,
+                _ => {
+                    return Err(ValidationError::InvalidValue {
+                        component: "VTODO",
+                        property: "PERCENT-COMPLETE",
+                    })
+                }
+            };
+            if completed && percent != 100 {
+                return Err(ValidationError::Inconsistent {
+                    component: "VTODO",
+                    property: "STATUS",
+                    other: "PERCENT-COMPLETE",
+                });
+            }
+        }
+        if completed {
+            validation::require("VTODO", &self.properties, &["COMPLETED"])?;
+        }
+        validation::check_end("VTODO", &self.properties, "DUE")?;
+        validation::check_start_before("VTODO", &self.properties, "DUE")?;
+        self.alarms.iter().try_for_each(Alarm::validate)
+    }
+}
+
+impl<'a> fmt::Display for ToDo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VTODO\r")?;
+        for property in &self.properties {
+            write!(f, "{}", property)?;
+        }
+        for component in &self.alarms {
+            write!(f, "{}", component)?;
+        }
+        writeln!(f, "END:VTODO\r")
+    }
+}
+
+impl<'a> From<ToDo<'a>> for Component<'a> {
+    fn from(component: ToDo<'a>) -> Self {
+        Component {
+            name: "VTODO".into(),
+            properties: component.properties,
+            subcomponents: component.alarms.into_iter().map(Component::from).collect(),
+        }
+    }
+}
+
+/// The `VJOURNAL` calendar component
+///
+/// A [`Journal`] component is a grouping of component properties that represent
+/// one or more descriptive text notes associated with a particular calendar
+/// date. (see [RFC5545 3.6.3. Journal Component](https://tools.ietf.org/html/rfc5545#section-3.6.3))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Journal<'a>(Vec<Property<'a>>);
+
+impl<'a> Journal<'a> {
+    /// Creates a new `VJOURNAL` calendar component. The `UID` and `DTSTAMP`
+    /// properties are required. A `UID` should be generated randomly for
+    /// security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Journal(vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()])
+    }
+
+    /// Creates a new `VJOURNAL` calendar component with a `DESCRIPTION`
+    /// property for each entry. The entries are escaped as text. Unlike other
+    /// components, a journal may contain several descriptions.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::Journal;
+    ///
+    /// let journal = Journal::with_entries(
+    ///     "b68378cf-872d-44f1-9703-5e3725c56e71",
+    ///     "19970901T130000Z",
+    ///     &["1. Staff meeting", "2. Telephone conference"],
+    /// );
+    /// assert_eq!(journal.get_all("DESCRIPTION").count(), 2);
+    /// ```
+    pub fn with_entries<U, D>(uid: U, dtstamp: D, entries: &[&'a str]) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        let mut journal = Self::new(uid, dtstamp);
+        for &entry in entries {
+            journal.push(Description::new(escape_text(entry)));
+        }
+        journal
+    }
+
+    /// Creates a new `VJOURNAL` calendar component with a random `UID` (see
+    /// [`UID::random()`]). The `DTSTAMP` property is required.
+    #[cfg(feature = "uuid")]
+    pub fn with_random_uid<D>(dtstamp: D) -> Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        Self::new(random_uid(), dtstamp)
+    }
+
+    /// Adds a property to the journal. RFC5545 and RFC7986 specify which
+    /// properties can be added to a journal.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.0.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the journal. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the journal. All properties with the same name are
+    /// replaced by it, or it is added if the journal does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the journal with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the journal with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the journal contains the required properties. `DTSTART`
+    /// is not required and `DESCRIPTION` may occur several times, e.g. for
+    /// the entries of meeting minutes.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VJOURNAL", &self.0)?;
+        validation::require("VJOURNAL", &self.0, &["UID", "DTSTAMP"])?;
+        validation::check_date_lists("VJOURNAL", &self.0)
+    }
+}
+
+impl<'a> fmt::Display for Journal<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VJOURNAL\r")?;
+        for property in &self.0 {
+            write!(f, "{}", property)?;
+        }
+        writeln!(f, "END:VJOURNAL\r")
+    }
+}
+
+impl<'a> From<Journal<'a>> for Component<'a> {
+    fn from(component: Journal<'a>) -> Self {
+        Component {
+            name: "VJOURNAL".into(),
+            properties: component.0,
+            subcomponents: Vec::new(),
+        }
+    }
+}
+
+/// The `VFREEBUSY` calendar component
+///
+///  A [`FreeBusy`] component is a grouping of component properties that
+/// represents either a request for free or busy time information, a reply to a
+/// request for free or busy time information, or a published set of busy time
+/// information. (see [RFC5545 3.6.4. Free/Busy Component Component](https://tools.ietf.org/html/rfc5545#section-3.6.4))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FreeBusy<'a>(Vec<Property<'a>>);
+
+impl<'a> FreeBusy<'a> {
+    /// Creates a new `VFREEBUSY` calendar component. The `UID` and `DTSTAMP`
+    /// properties are required. A `UID` should be generated randomly for
+    /// security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        FreeBusy(vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()])
+    }
+
+    /// Creates a new `VFREEBUSY` calendar component with a random `UID` (see
+    /// [`UID::random()`]). The `DTSTAMP` property is required.
+    #[cfg(feature = "uuid")]
+    pub fn with_random_uid<D>(dtstamp: D) -> Self
+    where
+        D: Into<Cow<'a, str>>,
+    {
+        Self::new(random_uid(), dtstamp)
     }
 
     /// Adds a property to the free busy schedule. The RFC5545 specifies which
@@ -312,68 +1537,982 @@ impl<'a> FreeBusy<'a> {
     {
         self.0.push(property.into());
     }
+
+    /// Removes all properties with the given name from the free busy schedule. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the free busy schedule. All properties with the same name are
+    /// replaced by it, or it is added if the free busy schedule does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the free busy schedule with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the free busy schedule with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the free busy schedule contains the required properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VFREEBUSY", &self.0)?;
+        validation::require("VFREEBUSY", &self.0, &["UID", "DTSTAMP"])
+    }
+}
+
+impl<'a> fmt::Display for FreeBusy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VFREEBUSY\r")?;
+        for property in &self.0 {
+            write!(f, "{}", property)?;
+        }
+        writeln!(f, "END:VFREEBUSY\r")
+    }
+}
+
+impl<'a> From<FreeBusy<'a>> for Component<'a> {
+    fn from(component: FreeBusy<'a>) -> Self {
+        Component {
+            name: "VFREEBUSY".into(),
+            properties: component.0,
+            subcomponents: Vec::new(),
+        }
+    }
+}
+
+/// The `VTIMEZONE` calendar component
+///
+///  A [`TimeZone`] component is unambiguously defined by the set of time
+/// measurement rules ([`Standard`] or [`Daylight`]) determined by the governing
+/// body for a given geographic area. (see [RFC5545 3.6.5. Time Zone Component Component](https://tools.ietf.org/html/rfc5545#section-3.6.5))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeZone<'a> {
+    properties: Vec<Property<'a>>,
+    zone_times: Vec<ZoneTime<'a>>,
+}
+
+impl<'a> TimeZone<'a> {
+    /// Creates a new `VTIMEZONE` calendar component from `TZID` property and
+    /// `STANDARD` sub-component. More definitions can be added with
+    /// [`TimeZone::add_standard()`] or [`TimeZone::add_daylight()`].
+    pub fn standard<S>(tzid: S, definition: Standard<'a>) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            properties: vec![TzID::new(tzid).into()],
+            zone_times: vec![ZoneTime::Standard(definition)],
+        }
+    }
+
+    /// Creates a new `VTIMEZONE` calendar component from `TZID` property and
+    /// `DAYLIGHT` sub-component. More definitions can be added with
+    /// [`TimeZone::add_standard()`] or [`TimeZone::add_daylight()`].
+    pub fn daylight<S>(tzid: S, definition: Daylight<'a>) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            properties: vec![TzID::new(tzid).into()],
+            zone_times: vec![ZoneTime::Daylight(definition)],
+        }
+    }
+
+    /// Creates a new `VTIMEZONE` calendar component for a zone without
+    /// daylight saving time. It has a single `STANDARD` definition with the
+    /// offset from UTC in minutes, e.g. `-300` for `-0500`.
+    pub fn fixed_offset<S>(tzid: S, offset: i16) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let offset = UtcOffset::from_minutes(offset).to_string();
+        Self::standard(
+            tzid,
+            Standard::new("19700101T000000", offset.clone(), offset),
+        )
+    }
+
+    /// Creates the `VTIMEZONE` calendar component for `America/New_York` with
+    /// the daylight saving time rules of the United States since 2007.
+    pub fn us_eastern() -> Self {
+        Self::united_states("America/New_York", -300, "EST", "EDT")
+    }
+
+    /// Creates the `VTIMEZONE` calendar component for `America/Chicago` with
+    /// the daylight saving time rules of the United States since 2007.
+    pub fn us_central() -> Self {
+        Self::united_states("America/Chicago", -360, "CST", "CDT")
+    }
+
+    /// Creates the `VTIMEZONE` calendar component for `America/Denver` with
+    /// the daylight saving time rules of the United States since 2007.
+    pub fn us_mountain() -> Self {
+        Self::united_states("America/Denver", -420, "MST", "MDT")
+    }
+
+    /// Creates the `VTIMEZONE` calendar component for `America/Los_Angeles`
+    /// with the daylight saving time rules of the United States since 2007.
+    pub fn us_pacific() -> Self {
+        Self::united_states("America/Los_Angeles", -480, "PST", "PDT")
+    }
+
+    /// Creates the `VTIMEZONE` calendar component for `Europe/Berlin` with the
+    /// daylight saving time rules of the European Union since 1996.
+    pub fn central_europe() -> Self {
+        Self::with_daylight_saving(
+            "Europe/Berlin",
+            60,
+            (
+                "CET",
+                "19961027T030000",
+                "FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU",
+            ),
+            (
+                "CEST",
+                "19810329T020000",
+                "FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU",
+            ),
+        )
+    }
+
+    fn united_states(tzid: &'a str, offset: i16, standard: &'a str, daylight: &'a str) -> Self {
+        Self::with_daylight_saving(
+            tzid,
+            offset,
+            (
+                standard,
+                "20071104T020000",
+                "FREQ=YEARLY;BYMONTH=11;BYDAY=1SU",
+            ),
+            (
+                daylight,
+                "20070311T020000",
+                "FREQ=YEARLY;BYMONTH=3;BYDAY=2SU",
+            ),
+        )
+    }
+
+    // Daylight saving time is one hour ahead of the standard offset. The rules
+    // consist of the name, the first onset and the recurrence of the onset.
+    fn with_daylight_saving(
+        tzid: &'a str,
+        offset: i16,
+        standard: (&'a str, &'a str, &'a str),
+        daylight: (&'a str, &'a str, &'a str),
+    ) -> Self {
+        let standard_offset = UtcOffset::from_minutes(offset).to_string();
+        let daylight_offset = UtcOffset::from_minutes(offset + 60).to_string();
+
+        let mut standard_time =
+            Standard::new(standard.1, daylight_offset.clone(), standard_offset.clone());
+        standard_time.push(RRule::new(standard.2));
+        standard_time.push(TzName::new(standard.0));
+        let mut daylight_time = Daylight::new(daylight.1, standard_offset, daylight_offset);
+        daylight_time.push(RRule::new(daylight.2));
+        daylight_time.push(TzName::new(daylight.0));
+
+        let mut timezone = Self::standard(tzid, standard_time);
+        timezone.add_daylight(daylight_time);
+        timezone
+    }
+
+    /// Adds a property to the time zone. The RFC5545 specifies which
+    /// properties can be added to a time zone.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.properties.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the time zone. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the time zone. All properties with the same name are
+    /// replaced by it, or it is added if the time zone does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the time zone with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the time zone with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds an additional `STANDARD` definition to the time zone. For more
+    /// time zone definitions, the IANA database could prove helpful.
+    pub fn add_standard(&mut self, definition: Standard<'a>) {
+        self.zone_times.push(ZoneTime::Standard(definition));
+    }
+
+    /// Adds an additional `DAYLIGHT` definition to the time zone. For more
+    /// time zone definitions, the IANA database could prove helpful.
+    pub fn add_daylight(&mut self, definition: Daylight<'a>) {
+        self.zone_times.push(ZoneTime::Daylight(definition));
+    }
+
+    /// Checks that the time zone contains the required properties and at least
+    /// one `STANDARD` or `DAYLIGHT` definition.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VTIMEZONE", &self.properties)?;
+        validation::require("VTIMEZONE", &self.properties, &["TZID"])?;
+        if self.zone_times.is_empty() {
+            return Err(ValidationError::MissingComponent {
+                component: "VTIMEZONE",
+                subcomponent: "STANDARD or DAYLIGHT",
+            });
+        }
+        self.zone_times
+            .iter()
+            .try_for_each(|zone_time| match zone_time {
+                ZoneTime::Standard(p) => p.validate(),
+                ZoneTime::Daylight(p) => p.validate(),
+            })
+    }
+}
+
+impl<'a> fmt::Display for TimeZone<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VTIMEZONE\r")?;
+        for property in &self.properties {
+            write!(f, "{}", property)?;
+        }
+        for component in &self.zone_times {
+            write!(f, "{}", component)?;
+        }
+        writeln!(f, "END:VTIMEZONE\r")
+    }
+}
+
+impl<'a> From<TimeZone<'a>> for Component<'a> {
+    fn from(component: TimeZone<'a>) -> Self {
+        Component {
+            name: "VTIMEZONE".into(),
+            properties: component.properties,
+            subcomponents: component
+                .zone_times
+                .into_iter()
+                .map(Component::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum ZoneTime<'a> {
+    /// Standard Time
+    Standard(Standard<'a>),
+    /// Daylight Saving Time
+    Daylight(Daylight<'a>),
+}
+
+impl<'a> fmt::Display for ZoneTime<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZoneTime::Daylight(p) => write!(f, "{}", p),
+            ZoneTime::Standard(p) => write!(f, "{}", p),
+        }
+    }
+}
+
+impl<'a> From<ZoneTime<'a>> for Component<'a> {
+    fn from(component: ZoneTime<'a>) -> Self {
+        match component {
+            ZoneTime::Daylight(p) => Self::from(p),
+            ZoneTime::Standard(p) => Self::from(p),
+        }
+    }
+}
+
+// Required properties of STANDARD and DAYLIGHT
+const ZONE_TIME_PROPERTIES: &[&str] = &["DTSTART", "TZOFFSETFROM", "TZOFFSETTO"];
+
+/// The `STANDARD` calendar sub-component of `VTIMEZONE`
+///
+/// A [`Standard`] component is a sub-component of the [`TimeZone`] component
+/// which describes rules for standard time, also known as winter time.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Standard<'a>(Vec<Property<'a>>);
+
+impl<'a> Standard<'a> {
+    /// Creates a new `STANDARD` sub-component. The properties `DTSTART`,
+    /// `TZOFFSETFROM` and `TZOFFSETTO` are required.
+    pub fn new<S, T, F>(dtstart: S, tz_offset_from: F, tz_offset_to: T) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Standard(vec![
+            DtStart::new(dtstart).into(),
+            TzOffsetFrom::new(tz_offset_from).into(),
+            TzOffsetTo::new(tz_offset_to).into(),
+        ])
+    }
+
+    /// Adds a property to the zone time. The RFC5545 specifies which
+    /// properties can be added to a zone time.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.0.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the zone time. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the zone time. All properties with the same name are
+    /// replaced by it, or it is added if the zone time does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the zone time with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the zone time with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the zone time contains the required properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("STANDARD", &self.0)?;
+        validation::require("STANDARD", &self.0, ZONE_TIME_PROPERTIES)?;
+        validation::check_date_lists("STANDARD", &self.0)
+    }
+}
+
+impl<'a> fmt::Display for Standard<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:STANDARD\r")?;
+        for property in &self.0 {
+            write!(f, "{}", property)?;
+        }
+        writeln!(f, "END:STANDARD\r")
+    }
+}
+
+impl<'a> From<Standard<'a>> for Component<'a> {
+    fn from(component: Standard<'a>) -> Self {
+        Component {
+            name: "STANDARD".into(),
+            properties: component.0,
+            subcomponents: Vec::new(),
+        }
+    }
+}
+
+/// The `DAYLIGHT` calendar sub-component of `VTIMEZONE`
+///
+/// A `[`Daylight`] component is a sub-component of the [`TimeZone`] component
+/// which describes rules for daylight saving time, also known as advanced time,
+/// summer time, or legal time in certain countries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Daylight<'a>(Vec<Property<'a>>);
+
+impl<'a> Daylight<'a> {
+    /// Creates a new `DAYLIGHT` sub-component. The properties `DTSTART`,
+    /// `TZOFFSETFROM` and `TZOFFSETTO` are required.
+    pub fn new<S, T, F>(dtstart: S, tz_offset_from: F, tz_offset_to: T) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        F: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Daylight(vec![
+            DtStart::new(dtstart).into(),
+            TzOffsetFrom::new(tz_offset_from).into(),
+            TzOffsetTo::new(tz_offset_to).into(),
+        ])
+    }
+
+    /// Adds a property to the zone time. The RFC5545 specifies which
+    /// properties can be added to a zone time.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.0.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the zone time. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the zone time. All properties with the same name are
+    /// replaced by it, or it is added if the zone time does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the zone time with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the zone time with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the zone time contains the required properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("DAYLIGHT", &self.0)?;
+        validation::require("DAYLIGHT", &self.0, ZONE_TIME_PROPERTIES)?;
+        validation::check_date_lists("DAYLIGHT", &self.0)
+    }
+}
+
+impl<'a> fmt::Display for Daylight<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:DAYLIGHT\r")?;
+        for property in &self.0 {
+            write!(f, "{}", property)?;
+        }
+        writeln!(f, "END:DAYLIGHT\r")
+    }
+}
+
+impl<'a> From<Daylight<'a>> for Component<'a> {
+    fn from(component: Daylight<'a>) -> Self {
+        Component {
+            name: "DAYLIGHT".into(),
+            properties: component.0,
+            subcomponents: Vec::new(),
+        }
+    }
+}
+
+/// The `VAVAILABILITY` calendar component
+///
+/// An [`Availability`] component is a grouping of component properties and
+/// [`Available`] sub-components that describe the availability of a calendar
+/// user over a period of time. (see [RFC7953 3.1. VAVAILABILITY Component](https://tools.ietf.org/html/rfc7953#section-3.1))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Availability<'a> {
+    properties: Vec<Property<'a>>,
+    available: Vec<Available<'a>>,
+}
+
+impl<'a> Availability<'a> {
+    /// Creates a new `VAVAILABILITY` calendar component. The `UID` and
+    /// `DTSTAMP` properties are required. A `UID` should be generated randomly
+    /// for security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Self {
+            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
+            available: Vec::new(),
+        }
+    }
+
+    /// Adds a property to the availability. The RFC7953 specifies which
+    /// properties can be added to an availability.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.properties.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the availability.
+    /// Returns whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the availability. All properties with the same name
+    /// are replaced by it, or it is added if the availability does not contain
+    /// one yet. This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the availability with the given name.
+    /// The name is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the availability with the
+    /// given name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds an `AVAILABLE` time span to the availability.
+    pub fn add_available(&mut self, available: Available<'a>) {
+        self.available.push(available);
+    }
+
+    /// Checks that the availability and its time spans contain the required
+    /// properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VAVAILABILITY", &self.properties)?;
+        validation::require("VAVAILABILITY", &self.properties, &["UID", "DTSTAMP"])?;
+        self.available.iter().try_for_each(Available::validate)
+    }
+}
+
+impl<'a> fmt::Display for Availability<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VAVAILABILITY\r")?;
+        for property in &self.properties {
+            write!(f, "{}", property)?;
+        }
+        for component in &self.available {
+            write!(f, "{}", component)?;
+        }
+        writeln!(f, "END:VAVAILABILITY\r")
+    }
+}
+
+impl<'a> From<Availability<'a>> for Component<'a> {
+    fn from(component: Availability<'a>) -> Self {
+        Component {
+            name: "VAVAILABILITY".into(),
+            properties: component.properties,
+            subcomponents: component
+                .available
+                .into_iter()
+                .map(Component::from)
+                .collect(),
+        }
+    }
+}
+
+/// The `AVAILABLE` calendar sub-component of `VAVAILABILITY`
+///
+/// An [`Available`] component defines a time span within an [`Availability`]
+/// where the calendar user is available. (see [RFC7953 3.1. AVAILABLE Component](https://tools.ietf.org/html/rfc7953#section-3.1))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Available<'a>(Vec<Property<'a>>);
+
+impl<'a> Available<'a> {
+    /// Creates a new `AVAILABLE` sub-component. The properties `UID`,
+    /// `DTSTAMP` and `DTSTART` are required. Additionally either `DTEND` or
+    /// `DURATION` should be added. `DTSTART` is passed as property because it
+    /// usually contains a `TZID` parameter.
+    pub fn new<U, D>(uid: U, dtstamp: D, dtstart: DtStart<'a>) -> Self
+    where
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
+    {
+        Available(vec![
+            UID::new(uid).into(),
+            DtStamp::new(dtstamp).into(),
+            dtstart.into(),
+        ])
+    }
+
+    /// Adds a property to the time span. The RFC7953 specifies which
+    /// properties can be added to a time span.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.0.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the time span. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the time span. All properties with the same name are
+    /// replaced by it, or it is added if the time span does not contain one
+    /// yet. This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the time span with the given name. The
+    /// name is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the time span with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the time span contains the required properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("AVAILABLE", &self.0)?;
+        validation::require("AVAILABLE", &self.0, &["UID", "DTSTAMP", "DTSTART"])
+    }
 }
 
-impl<'a> fmt::Display for FreeBusy<'a> {
+impl<'a> fmt::Display for Available<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VFREEBUSY\r")?;
+        writeln!(f, "BEGIN:AVAILABLE\r")?;
         for property in &self.0 {
             write!(f, "{}", property)?;
         }
-        writeln!(f, "END:VFREEBUSY\r")
+        writeln!(f, "END:AVAILABLE\r")
     }
 }
 
-impl<'a> From<FreeBusy<'a>> for Component<'a> {
-    fn from(component: FreeBusy<'a>) -> Self {
+impl<'a> From<Available<'a>> for Component<'a> {
+    fn from(component: Available<'a>) -> Self {
         Component {
-            name: "VFREEBUSY".into(),
+            name: "AVAILABLE".into(),
             properties: component.0,
             subcomponents: Vec::new(),
         }
     }
 }
 
-/// The `VTIMEZONE` calendar component
+/// The `VPOLL` calendar component (experimental)
 ///
-///  A [`TimeZone`] component is unambiguously defined by the set of time
-/// measurement rules ([`Standard`] or [`Daylight`]) determined by the governing
-/// body for a given geographic area. (see [RFC5545 3.6.5. Time Zone Component Component](https://tools.ietf.org/html/rfc5545#section-3.6.5))
+/// A [`Poll`] component is a grouping of component properties and candidate
+/// [`Event`] sub-components that voters choose from to find a time for a
+/// meeting. The candidates are identified by the `POLL-ITEM-ID` property.
+///
+/// `VPOLL` is specified by the draft [VPOLL: Consensus Scheduling Component
+/// for iCalendar](https://datatracker.ietf.org/doc/draft-ietf-calext-vpoll/)
+/// and not by an RFC yet, so the component may change in future versions.
+/// Voting with `VVOTER` components is not supported, such polls are kept as
+/// generic [`Component`] when parsed.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct TimeZone<'a> {
+pub struct Poll<'a> {
     properties: Vec<Property<'a>>,
-    zone_times: Vec<ZoneTime<'a>>,
+    candidates: Vec<Event<'a>>,
 }
 
-impl<'a> TimeZone<'a> {
-    /// Creates a new `VTIMEZONE` calendar component from `TZID` property and
-    /// `STANDARD` sub-component. More definitions can be added with
-    /// [`TimeZone::add_standard()`] or [`TimeZone::add_daylight()`].
-    pub fn standard<S>(tzid: S, definition: Standard<'a>) -> Self
+impl<'a> Poll<'a> {
+    /// Creates a new `VPOLL` calendar component. The `UID` and `DTSTAMP`
+    /// properties are required. A `UID` should be generated randomly for
+    /// security reasons.
+    pub fn new<U, D>(uid: U, dtstamp: D) -> Self
     where
-        S: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+        D: Into<Cow<'a, str>>,
     {
         Self {
-            properties: vec![TzID::new(tzid).into()],
-            zone_times: vec![ZoneTime::Standard(definition)],
+            properties: vec![UID::new(uid).into(), DtStamp::new(dtstamp).into()],
+            candidates: Vec::new(),
         }
     }
 
-    /// Creates a new `VTIMEZONE` calendar component from `TZID` property and
-    /// `DAYLIGHT` sub-component. More definitions can be added with
-    /// [`TimeZone::add_standard()`] or [`TimeZone::add_daylight()`].
-    pub fn daylight<S>(tzid: S, definition: Daylight<'a>) -> Self
+    /// Adds a property to the poll like `POLL-MODE` or `POLL-PROPERTIES`.
+    pub fn push<P>(&mut self, property: P)
     where
-        S: Into<Cow<'a, str>>,
+        P: Into<Property<'a>>,
+    {
+        self.properties.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the poll. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
+    }
+
+    /// Sets a property of the poll. All properties with the same name are
+    /// replaced by it, or it is added if the poll does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the poll with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the poll with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds a candidate `VEVENT` to the poll. The candidate should contain a
+    /// `POLL-ITEM-ID` property that is unique within the poll.
+    pub fn add_candidate(&mut self, candidate: Event<'a>) {
+        self.candidates.push(candidate);
+    }
+
+    /// Returns an iterator over the candidate `VEVENT` components.
+    pub fn candidates(&self) -> impl Iterator<Item = &Event<'a>> {
+        self.candidates.iter()
+    }
+
+    /// Checks that the poll and its candidates contain the required
+    /// properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VPOLL", &self.properties)?;
+        validation::require("VPOLL", &self.properties, &["UID", "DTSTAMP"])?;
+        self.candidates.iter().try_for_each(Event::validate)
+    }
+}
+
+impl<'a> fmt::Display for Poll<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VPOLL\r")?;
+        for property in &self.properties {
+            write!(f, "{}", property)?;
+        }
+        for candidate in &self.candidates {
+            write!(f, "{}", candidate)?;
+        }
+        writeln!(f, "END:VPOLL\r")
+    }
+}
+
+impl<'a> From<Poll<'a>> for Component<'a> {
+    fn from(component: Poll<'a>) -> Self {
+        Component {
+            name: "VPOLL".into(),
+            properties: component.properties,
+            subcomponents: component
+                .candidates
+                .into_iter()
+                .map(Component::from)
+                .collect(),
+        }
+    }
+}
+
+/// The `VALARM` calendar sub-component of `VEVENT` and `VTODO`.
+///
+/// An [`Alarm`] component is a grouping of component properties that is a
+/// reminder or alarm for an [`Event`] or a [`ToDo`]. For example, it may be
+/// used to define a reminder for a pending event or an overdue to-do. (see [RFC5545 3.6.6. Alarm Component](https://tools.ietf.org/html/rfc5545#section-3.6.6))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Alarm<'a>(Vec<Property<'a>>);
+
+// The specific constructors use the specific property builder types since the
+// required properties can have defined parameters.
+impl<'a> Alarm<'a> {
+    /// Creates a new `VALARM` calendar component. The `ACTION` and `TRIGGER`
+    /// properties are required.
+    pub fn new(action: Action<'a>, trigger: Trigger<'a>) -> Self {
+        Alarm(vec![action.into(), trigger.into()])
+    }
+
+    /// Creates a new audio alarm. The `TRIGGER` property is required.
+    pub fn audio(trigger: Trigger<'a>) -> Self {
+        Self::new(Action::audio(), trigger)
+    }
+
+    /// Creates a new display alarm. The `TRIGGER` and `DESCRIPTION` properties
+    /// are required.
+    pub fn display(trigger: Trigger<'a>, description: Description<'a>) -> Self {
+        Alarm(vec![
+            Action::display().into(),
+            trigger.into(),
+            description.into(),
+        ])
+    }
+
+    /// Creates a new email alarm. The `TRIGGER`, `DESCRIPTION` and `SUMMARY`
+    /// properties are required. At least one `ATTENDEE` property for the
+    /// recipient has to be added as well.
+    pub fn email(trigger: Trigger<'a>, description: Description<'a>, summary: Summary<'a>) -> Self {
+        Alarm(vec![
+            Action::email().into(),
+            trigger.into(),
+            description.into(),
+            summary.into(),
+        ])
+    }
+
+    /// Adds a property to the alarm. The RFC5545 specifies which property can
+    /// be added depending on the kind of alarm.
+    pub fn push<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        self.0.push(property.into());
+    }
+
+    /// Removes all properties with the given name from the alarm. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the alarm. All properties with the same name are
+    /// replaced by it, or it is added if the alarm does not contain one yet.
+    /// This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the alarm with the given name. The name
+    /// is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the alarm with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    fn sort(&mut self) {
+        sort_properties(&mut self.0, &["ACTION", "TRIGGER"]);
+    }
+
+    /// Checks that the alarm contains the required properties. Which
+    /// properties are required besides `ACTION` and `TRIGGER` depends on the
+    /// action.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VALARM", &self.0)?;
+        validation::require("VALARM", &self.0, &["ACTION", "TRIGGER"])?;
+        let action = validation::find(&self.0, "ACTION").map(|action| &action.value);
+        match action {
+            Some(action) if action.eq_ignore_ascii_case("DISPLAY") => {
+                validation::require("VALARM", &self.0, &["DESCRIPTION"])
+            }
+            Some(action) if action.eq_ignore_ascii_case("EMAIL") => {
+                validation::require("VALARM", &self.0, &["DESCRIPTION", "SUMMARY", "ATTENDEE"])
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> fmt::Display for Alarm<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "BEGIN:VALARM\r")?;
+        for property in &self.0 {
+            write!(f, "{}", property)?;
+        }
+        writeln!(f, "END:VALARM\r")
+    }
+}
+
+impl<'a> From<Alarm<'a>> for Component<'a> {
+    fn from(component: Alarm<'a>) -> Self {
+        Component {
+            name: "VALARM".into(),
+            properties: component.0,
+            subcomponents: Vec::new(),
+        }
+    }
+}
+
+/// The `VPARTICIPANT` calendar sub-component of `VEVENT`
+///
+/// A [`Participant`] component describes a person or resource that takes part
+/// in an [`Event`] in more detail than an `ATTENDEE` property, possibly
+/// including a [`StructuredLocation`]. (see [RFC9073 7.1. Participant](https://tools.ietf.org/html/rfc9073#section-7.1))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Participant<'a> {
+    properties: Vec<Property<'a>>,
+    locations: Vec<StructuredLocation<'a>>,
+}
+
+impl<'a> Participant<'a> {
+    /// Creates a new `VPARTICIPANT` sub-component. The `UID` and
+    /// `PARTICIPANT-TYPE` properties are required. A `UID` should be generated
+    /// randomly for security reasons.
+    pub fn new<U>(uid: U, participant_type: ParticipantType<'a>) -> Self
+    where
+        U: Into<Cow<'a, str>>,
     {
         Self {
-            properties: vec![TzID::new(tzid).into()],
-            zone_times: vec![ZoneTime::Daylight(definition)],
+            properties: vec![UID::new(uid).into(), participant_type.into()],
+            locations: Vec::new(),
         }
     }
 
-    /// Adds a property to the time zone. The RFC5545 specifies which
-    /// properties can be added to a time zone.
+    /// Adds a property to the participant. The RFC9073 specifies which
+    /// properties can be added to a participant, e.g. `CALENDAR-ADDRESS`.
     pub fn push<P>(&mut self, property: P)
     where
         P: Into<Property<'a>>,
@@ -381,39 +2520,104 @@ impl<'a> TimeZone<'a> {
         self.properties.push(property.into());
     }
 
-    /// Adds an additional `STANDARD` definition to the time zone. For more
-    /// time zone definitions, the IANA database could prove helpful.
-    pub fn add_standard(&mut self, definition: Standard<'a>) {
-        self.zone_times.push(ZoneTime::Standard(definition));
+    /// Removes all properties with the given name from the participant.
+    /// Returns whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.properties, name)
     }
 
-    /// Adds an additional `DAYLIGHT` definition to the time zone. For more
-    /// time zone definitions, the IANA database could prove helpful.
-    pub fn add_daylight(&mut self, definition: Daylight<'a>) {
-        self.zone_times.push(ZoneTime::Daylight(definition));
+    /// Sets a property of the participant. All properties with the same name
+    /// are replaced by it, or it is added if the participant does not contain
+    /// one yet. This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.properties, property.into());
+    }
+
+    /// Returns the first property of the participant with the given name. The
+    /// name is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.properties, name)
+    }
+
+    /// Returns an iterator over all properties of the participant with the
+    /// given name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.properties
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Adds a `VLOCATION` to the participant, e.g. where a speaker is.
+    pub fn add_location(&mut self, location: StructuredLocation<'a>) {
+        self.locations.push(location);
+    }
+
+    // Checks if a generic component can be converted into a participant.
+    fn is_participant(component: &Component) -> bool {
+        component.name.eq_ignore_ascii_case("VPARTICIPANT")
+            && component
+                .subcomponents
+                .iter()
+                .all(|c| c.subcomponents.is_empty() && c.name.eq_ignore_ascii_case("VLOCATION"))
+    }
+
+    fn from_component(component: Component<'a>) -> Self {
+        Participant {
+            properties: component.properties,
+            locations: component
+                .subcomponents
+                .into_iter()
+                .map(|c| StructuredLocation(c.properties))
+                .collect(),
+        }
+    }
+
+    fn sort(&mut self) {
+        sort_properties(&mut self.properties, &["UID", "PARTICIPANT-TYPE"]);
+        for location in &mut self.locations {
+            sort_properties(&mut location.0, &["UID"]);
+        }
+    }
+
+    /// Checks that the participant and its locations contain the required
+    /// properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VPARTICIPANT", &self.properties)?;
+        validation::require(
+            "VPARTICIPANT",
+            &self.properties,
+            &["UID", "PARTICIPANT-TYPE"],
+        )?;
+        self.locations
+            .iter()
+            .try_for_each(StructuredLocation::validate)
     }
 }
 
-impl<'a> fmt::Display for TimeZone<'a> {
+impl<'a> fmt::Display for Participant<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VTIMEZONE\r")?;
+        writeln!(f, "BEGIN:VPARTICIPANT\r")?;
         for property in &self.properties {
             write!(f, "{}", property)?;
         }
-        for component in &self.zone_times {
+        for component in &self.locations {
             write!(f, "{}", component)?;
         }
-        writeln!(f, "END:VTIMEZONE\r")
+        writeln!(f, "END:VPARTICIPANT\r")
     }
 }
 
-impl<'a> From<TimeZone<'a>> for Component<'a> {
-    fn from(component: TimeZone<'a>) -> Self {
+impl<'a> From<Participant<'a>> for Component<'a> {
+    fn from(component: Participant<'a>) -> Self {
         Component {
-            name: "VTIMEZONE".into(),
+            name: "VPARTICIPANT".into(),
             properties: component.properties,
             subcomponents: component
-                .zone_times
+                .locations
                 .into_iter()
                 .map(Component::from)
                 .collect(),
@@ -421,208 +2625,432 @@ impl<'a> From<TimeZone<'a>> for Component<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-enum ZoneTime<'a> {
-    /// Standard Time
-    Standard(Standard<'a>),
-    /// Daylight Saving Time
-    Daylight(Daylight<'a>),
-}
-
-impl<'a> fmt::Display for ZoneTime<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ZoneTime::Daylight(p) => write!(f, "{}", p),
-            ZoneTime::Standard(p) => write!(f, "{}", p),
-        }
-    }
-}
-
-impl<'a> From<ZoneTime<'a>> for Component<'a> {
-    fn from(component: ZoneTime<'a>) -> Self {
-        match component {
-            ZoneTime::Daylight(p) => Self::from(p),
-            ZoneTime::Standard(p) => Self::from(p),
-        }
-    }
-}
-
-/// The `STANDARD` calendar sub-component of `VTIMEZONE`
+/// The `VLOCATION` calendar sub-component of `VEVENT` and `VPARTICIPANT`
 ///
-/// A [`Standard`] component is a sub-component of the [`TimeZone`] component
-/// which describes rules for standard time, also known as winter time.
+/// A [`StructuredLocation`] component describes a location of an [`Event`] or
+/// a [`Participant`] in more detail than a `LOCATION` property, e.g. with a
+/// `LOCATION-TYPE` and a `URL`. (see [RFC9073 7.2. Location](https://tools.ietf.org/html/rfc9073#section-7.2))
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Standard<'a>(Vec<Property<'a>>);
+pub struct StructuredLocation<'a>(Vec<Property<'a>>);
 
-impl<'a> Standard<'a> {
-    /// Creates a new `STANDARD` sub-component. The properties `DTSTART`,
-    /// `TZOFFSETFROM` and `TZOFFSETTO` are required.
-    pub fn new<S, T, F>(dtstart: S, tz_offset_from: F, tz_offset_to: T) -> Self
+impl<'a> StructuredLocation<'a> {
+    /// Creates a new `VLOCATION` sub-component. The `UID` property is
+    /// required.
+    pub fn new<U>(uid: U) -> Self
     where
-        S: Into<Cow<'a, str>>,
-        F: Into<Cow<'a, str>>,
-        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
     {
-        Standard(vec![
-            DtStart::new(dtstart).into(),
-            TzOffsetFrom::new(tz_offset_from).into(),
-            TzOffsetTo::new(tz_offset_to).into(),
-        ])
+        StructuredLocation(vec![UID::new(uid).into()])
     }
 
-    /// Adds a property to the zone time. The RFC5545 specifies which
-    /// properties can be added to a zone time.
+    /// Adds a property to the location. The RFC9073 specifies which properties
+    /// can be added to a location, e.g. `NAME` or `LOCATION-TYPE`.
     pub fn push<P>(&mut self, property: P)
     where
         P: Into<Property<'a>>,
     {
         self.0.push(property.into());
     }
+
+    /// Removes all properties with the given name from the location. Returns
+    /// whether a property was removed.
+    pub fn remove(&mut self, name: &str) -> bool {
+        remove_properties(&mut self.0, name)
+    }
+
+    /// Sets a property of the location. All properties with the same name are
+    /// replaced by it, or it is added if the location does not contain one
+    /// yet. This is meant for properties that may occur at most once, see
+    /// [`Event::set()`].
+    pub fn set<P>(&mut self, property: P)
+    where
+        P: Into<Property<'a>>,
+    {
+        set_property(&mut self.0, property.into());
+    }
+
+    /// Returns the first property of the location with the given name. The
+    /// name is case-insensitive.
+    pub fn get(&self, name: &str) -> Option<&Property<'a>> {
+        validation::find(&self.0, name)
+    }
+
+    /// Returns an iterator over all properties of the location with the given
+    /// name. The name is case-insensitive.
+    pub fn get_all<'b>(&'b self, name: &'b str) -> impl Iterator<Item = &'b Property<'a>> {
+        self.0
+            .iter()
+            .filter(move |property| property.key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the location contains the required properties.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validation::check_values("VLOCATION", &self.0)?;
+        validation::require("VLOCATION", &self.0, &["UID"])
+    }
 }
 
-impl<'a> fmt::Display for Standard<'a> {
+impl<'a> fmt::Display for StructuredLocation<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:STANDARD\r")?;
+        writeln!(f, "BEGIN:VLOCATION\r")?;
         for property in &self.0 {
             write!(f, "{}", property)?;
         }
-        writeln!(f, "END:STANDARD\r")
+        writeln!(f, "END:VLOCATION\r")
     }
 }
 
-impl<'a> From<Standard<'a>> for Component<'a> {
-    fn from(component: Standard<'a>) -> Self {
+impl<'a> From<StructuredLocation<'a>> for Component<'a> {
+    fn from(component: StructuredLocation<'a>) -> Self {
         Component {
-            name: "STANDARD".into(),
+            name: "VLOCATION".into(),
             properties: component.0,
             subcomponents: Vec::new(),
         }
     }
 }
 
-/// The `DAYLIGHT` calendar sub-component of `VTIMEZONE`
-///
-/// A `[`Daylight`] component is a sub-component of the [`TimeZone`] component
-/// which describes rules for daylight saving time, also known as advanced time,
-/// summer time, or legal time in certain countries.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Daylight<'a>(Vec<Property<'a>>);
+// Removes all properties with the name and returns whether there were any.
+fn remove_properties(properties: &mut Vec<Property>, name: &str) -> bool {
+    let len = properties.len();
+    properties.retain(|property| !property.key.eq_ignore_ascii_case(name));
+    properties.len() != len
+}
 
-impl<'a> Daylight<'a> {
-    /// Creates a new `DAYLIGHT` sub-component. The properties `DTSTART`,
-    /// `TZOFFSETFROM` and `TZOFFSETTO` are required.
-    pub fn new<S, T, F>(dtstart: S, tz_offset_from: F, tz_offset_to: T) -> Self
+fn tzid_parameters<'b>(properties: &'b [Property], references: &mut Vec<&'b str>) {
+    for property in properties {
+        let tzid = property
+            .parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("TZID"));
+        if let Some((_, value)) = tzid {
+            references.push(value);
+        }
+    }
+}
+
+fn component_tzid_parameters<'b>(component: &'b Component, references: &mut Vec<&'b str>) {
+    tzid_parameters(&component.properties, references);
+    for subcomponent in &component.subcomponents {
+        component_tzid_parameters(subcomponent, references);
+    }
+}
+
+// Replaces the properties with the same name at the position of the first one
+// or appends the property.
+fn set_property<'a>(properties: &mut Vec<Property<'a>>, property: Property<'a>) {
+    let position = properties
+        .iter()
+        .position(|p| p.key.eq_ignore_ascii_case(&property.key));
+    match position {
+        Some(index) => {
+            remove_properties(properties, &property.key);
+            properties.insert(index, property);
+        }
+        None => properties.push(property),
+    }
+}
+
+// The components are serialized like a generic `Component` with the name,
+// properties and sub-components.
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::{
+        Alarm, Availability, Available, CalendarComponent, Daylight, Event, FreeBusy, ICalendar,
+        Journal, Participant, Poll, Standard, StructuredLocation, TimeZone, ToDo, ZoneTime,
+    };
+    use crate::components::{Component, Property};
+    use alloc::vec::Vec;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    fn serialize_component<S, C>(
+        serializer: S,
+        name: &'static str,
+        properties: &[Property],
+        subcomponents: &[C],
+    ) -> Result<S::Ok, S::Error>
     where
-        S: Into<Cow<'a, str>>,
-        F: Into<Cow<'a, str>>,
-        T: Into<Cow<'a, str>>,
+        S: Serializer,
+        C: Serialize,
     {
-        Daylight(vec![
-            DtStart::new(dtstart).into(),
-            TzOffsetFrom::new(tz_offset_from).into(),
-            TzOffsetTo::new(tz_offset_to).into(),
-        ])
+        let mut state = serializer.serialize_struct("Component", 3)?;
+        state.serialize_field("name", name)?;
+        state.serialize_field("properties", properties)?;
+        state.serialize_field("subcomponents", subcomponents)?;
+        state.end()
     }
 
-    /// Adds a property to the zone time. The RFC5545 specifies which
-    /// properties can be added to a zone time.
-    pub fn push<P>(&mut self, property: P)
+    fn deserialize_component<'de, 'a, D>(
+        deserializer: D,
+        name: &str,
+    ) -> Result<Component<'a>, D::Error>
     where
-        P: Into<Property<'a>>,
+        D: Deserializer<'de>,
     {
-        self.0.push(property.into());
+        let component = Component::deserialize(deserializer)?;
+        if component.name.eq_ignore_ascii_case(name) {
+            Ok(component)
+        } else {
+            Err(D::Error::custom(format_args!(
+                "expected {} component, found {}",
+                name, component.name
+            )))
+        }
     }
-}
 
-impl<'a> fmt::Display for Daylight<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:DAYLIGHT\r")?;
-        for property in &self.0 {
-            write!(f, "{}", property)?;
+    // Deserializes a component without sub-components.
+    fn deserialize_properties<'de, 'a, D>(
+        deserializer: D,
+        name: &str,
+    ) -> Result<Vec<Property<'a>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let component = deserialize_component(deserializer, name)?;
+        if component.subcomponents.is_empty() {
+            Ok(component.properties)
+        } else {
+            Err(D::Error::custom(format_args!(
+                "{} component cannot contain sub-components",
+                name
+            )))
         }
-        writeln!(f, "END:DAYLIGHT\r")
     }
-}
 
-impl<'a> From<Daylight<'a>> for Component<'a> {
-    fn from(component: Daylight<'a>) -> Self {
-        Component {
-            name: "DAYLIGHT".into(),
-            properties: component.0,
-            subcomponents: Vec::new(),
+    fn invalid_subcomponents<E: Error>(name: &str) -> E {
+        E::custom(format_args!(
+            "{} component contains invalid sub-components",
+            name
+        ))
+    }
+
+    impl Serialize for ICalendar<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(serializer, "VCALENDAR", &self.properties, &self.components)
         }
     }
-}
 
-/// The `VALARM` calendar sub-component of `VEVENT` and `VTODO`.
-///
-/// An [`Alarm`] component is a grouping of component properties that is a
-/// reminder or alarm for an [`Event`] or a [`ToDo`]. For example, it may be
-/// used to define a reminder for a pending event or an overdue to-do. (see [RFC5545 3.6.6. Alarm Component](https://tools.ietf.org/html/rfc5545#section-3.6.6))
-#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Alarm<'a>(Vec<Property<'a>>);
+    impl<'de, 'a> Deserialize<'de> for ICalendar<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VCALENDAR")?;
+            Ok(ICalendar {
+                properties: component.properties,
+                components: component
+                    .subcomponents
+                    .into_iter()
+                    .map(CalendarComponent::from_component)
+                    .collect(),
+            })
+        }
+    }
 
-// The specific constructors use the specific property builder types since the
-// required properties can have defined parameters.
-impl<'a> Alarm<'a> {
-    /// Creates a new `VALARM` calendar component. The `ACTION` and `TRIGGER`
-    /// properties are required.
-    pub fn new(action: Action<'a>, trigger: Trigger<'a>) -> Self {
-        Alarm(vec![action.into(), trigger.into()])
+    impl Serialize for CalendarComponent<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                CalendarComponent::Event(c) => c.serialize(serializer),
+                CalendarComponent::ToDo(c) => c.serialize(serializer),
+                CalendarComponent::Journal(c) => c.serialize(serializer),
+                CalendarComponent::FreeBusy(c) => c.serialize(serializer),
+                CalendarComponent::TimeZone(c) => c.serialize(serializer),
+                CalendarComponent::Availability(c) => c.serialize(serializer),
+                CalendarComponent::Poll(c) => c.serialize(serializer),
+                CalendarComponent::Other(c) => c.serialize(serializer),
+            }
+        }
     }
 
-    /// Creates a new audio alarm. The `TRIGGER` property is required.
-    pub fn audio(trigger: Trigger<'a>) -> Self {
-        Self::new(Action::audio(), trigger)
+    impl<'de, 'a> Deserialize<'de> for CalendarComponent<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Component::deserialize(deserializer).map(CalendarComponent::from_component)
+        }
     }
 
-    /// Creates a new display alarm. The `TRIGGER` and `DESCRIPTION` properties
-    /// are required.
-    pub fn display(trigger: Trigger<'a>, description: Description<'a>) -> Self {
-        Alarm(vec![
-            Action::display().into(),
-            trigger.into(),
-            description.into(),
-        ])
+    impl Serialize for Event<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let alarms = self.alarms.iter().map(EventSubcomponent::Alarm);
+            let participants = self.participants.iter().map(EventSubcomponent::Participant);
+            let locations = self.locations.iter().map(EventSubcomponent::Location);
+            let subcomponents: Vec<_> = alarms.chain(participants).chain(locations).collect();
+            serialize_component(serializer, "VEVENT", &self.properties, &subcomponents)
+        }
     }
 
-    /// Creates a new email alarm. The `TRIGGER`, `DESCRIPTION` and `SUMMARY`
-    /// properties are required.
-    pub fn email(trigger: Trigger<'a>, description: Description<'a>, summary: Summary<'a>) -> Self {
-        Alarm(vec![
-            Action::email().into(),
-            trigger.into(),
-            description.into(),
-            summary.into(),
-        ])
+    // The sub-components of an event have different types.
+    enum EventSubcomponent<'b, 'a> {
+        Alarm(&'b Alarm<'a>),
+        Participant(&'b Participant<'a>),
+        Location(&'b StructuredLocation<'a>),
     }
 
-    /// Adds a property to the alarm. The RFC5545 specifies which property can
-    /// be added depending on the kind of alarm.
-    pub fn push<P>(&mut self, property: P)
-    where
-        P: Into<Property<'a>>,
-    {
-        self.0.push(property.into());
+    impl Serialize for EventSubcomponent<'_, '_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                EventSubcomponent::Alarm(c) => c.serialize(serializer),
+                EventSubcomponent::Participant(c) => c.serialize(serializer),
+                EventSubcomponent::Location(c) => c.serialize(serializer),
+            }
+        }
     }
-}
 
-impl<'a> fmt::Display for Alarm<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "BEGIN:VALARM\r")?;
-        for property in &self.0 {
-            write!(f, "{}", property)?;
+    impl Serialize for Participant<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(
+                serializer,
+                "VPARTICIPANT",
+                &self.properties,
+                &self.locations,
+            )
         }
-        writeln!(f, "END:VALARM\r")
     }
-}
 
-impl<'a> From<Alarm<'a>> for Component<'a> {
-    fn from(component: Alarm<'a>) -> Self {
-        Component {
-            name: "VALARM".into(),
-            properties: component.0,
-            subcomponents: Vec::new(),
+    impl<'de, 'a> Deserialize<'de> for Participant<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VPARTICIPANT")?;
+            if Participant::is_participant(&component) {
+                Ok(Participant::from_component(component))
+            } else {
+                Err(invalid_subcomponents("VPARTICIPANT"))
+            }
+        }
+    }
+
+    impl<'de, 'a> Deserialize<'de> for Event<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VEVENT")?;
+            match CalendarComponent::from_component(component) {
+                CalendarComponent::Event(event) => Ok(event),
+                _ => Err(invalid_subcomponents("VEVENT")),
+            }
+        }
+    }
+
+    impl Serialize for ToDo<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(serializer, "VTODO", &self.properties, &self.alarms)
+        }
+    }
+
+    impl<'de, 'a> Deserialize<'de> for ToDo<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VTODO")?;
+            match CalendarComponent::from_component(component) {
+                CalendarComponent::ToDo(todo) => Ok(todo),
+                _ => Err(invalid_subcomponents("VTODO")),
+            }
+        }
+    }
+
+    impl Serialize for TimeZone<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(serializer, "VTIMEZONE", &self.properties, &self.zone_times)
+        }
+    }
+
+    impl<'de, 'a> Deserialize<'de> for TimeZone<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VTIMEZONE")?;
+            match CalendarComponent::from_component(component) {
+                CalendarComponent::TimeZone(timezone) => Ok(timezone),
+                _ => Err(invalid_subcomponents("VTIMEZONE")),
+            }
+        }
+    }
+
+    impl Serialize for Availability<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(
+                serializer,
+                "VAVAILABILITY",
+                &self.properties,
+                &self.available,
+            )
+        }
+    }
+
+    impl<'de, 'a> Deserialize<'de> for Availability<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VAVAILABILITY")?;
+            match CalendarComponent::from_component(component) {
+                CalendarComponent::Availability(availability) => Ok(availability),
+                _ => Err(invalid_subcomponents("VAVAILABILITY")),
+            }
         }
     }
+
+    impl Serialize for Poll<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_component(serializer, "VPOLL", &self.properties, &self.candidates)
+        }
+    }
+
+    impl<'de, 'a> Deserialize<'de> for Poll<'a> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let component = deserialize_component(deserializer, "VPOLL")?;
+            match CalendarComponent::from_component(component) {
+                CalendarComponent::Poll(poll) => Ok(poll),
+                _ => Err(invalid_subcomponents("VPOLL")),
+            }
+        }
+    }
+
+    impl Serialize for ZoneTime<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                ZoneTime::Standard(p) => p.serialize(serializer),
+                ZoneTime::Daylight(p) => p.serialize(serializer),
+            }
+        }
+    }
+
+    // Components that consist only of properties
+    macro_rules! impl_serde_properties {
+        ($type:ident, $name:expr) => {
+            impl Serialize for $type<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_component::<_, Component>(serializer, $name, &self.0, &[])
+                }
+            }
+
+            impl<'de, 'a> Deserialize<'de> for $type<'a> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_properties(deserializer, $name).map($type)
+                }
+            }
+        };
+    }
+
+    impl_serde_properties!(Journal, "VJOURNAL");
+    impl_serde_properties!(FreeBusy, "VFREEBUSY");
+    impl_serde_properties!(Standard, "STANDARD");
+    impl_serde_properties!(Daylight, "DAYLIGHT");
+    impl_serde_properties!(Alarm, "VALARM");
+    impl_serde_properties!(Available, "AVAILABLE");
+    impl_serde_properties!(StructuredLocation, "VLOCATION");
+}
+
+// Required properties of most components
+const COMPONENT_PROPERTIES: &[&str] = &["UID", "DTSTAMP"];
+
+// Sorts the properties with the given names first and the others by name.
+// Properties with the same name are compared by value and parameters, so the
+// order does not depend on the insertion order.
+fn sort_properties(properties: &mut [Property], first: &[&str]) {
+    let rank = |property: &Property| {
+        first
+            .iter()
+            .position(|name| property.key.eq_ignore_ascii_case(name))
+            .unwrap_or(first.len())
+    };
+    properties.sort_by(|a, b| {
+        rank(a)
+            .cmp(&rank(b))
+            .then_with(|| a.key.to_ascii_uppercase().cmp(&b.key.to_ascii_uppercase()))
+            .then_with(|| a.cmp(b))
+    });
+}
+
+fn sort_component(component: &mut Component) {
+    sort_properties(&mut component.properties, COMPONENT_PROPERTIES);
+    component.subcomponents.iter_mut().for_each(sort_component);
 }
@@ -20,9 +20,21 @@
 //! # Features
 //! - `rfc7986` (enabled by default): adds properties from the newer
 //!   specification [RFC7986](https://tools.ietf.org/html/rfc7986)
+//! - `std` (enabled by default): adds writing to files and `io::Write`,
+//!   parsing from `io::Read` and the `CalendarWriter`. Without it the library
+//!   is `no_std` and only requires `alloc`.
+//! - `chrono`: adds constructors for date and date-time properties from
+//!   [`chrono`](https://crates.io/crates/chrono) types
+//! - `time`: adds constructors for date and date-time properties from
+//!   [`time`](https://crates.io/crates/time) types
+//! - `serde`: implements `Serialize` and `Deserialize` for calendars,
+//!   components and properties
+//! - `uuid`: adds constructors that generate random UIDs
+//! - `tz`: generates time zone definitions from the IANA time zone database of
+//!   [`chrono-tz`](https://crates.io/crates/chrono-tz)
 //!
 //! # Example
-//! ```
+//! ```no_run
 //! use ics::properties::{Comment, Status, Summary};
 //! use ics::{ICalendar, ToDo};
 //!
@@ -41,30 +53,67 @@
 //!     calendar.add_todo(todo);
 //!
 //!     // Write `calendar` to a file.
+//! #   #[cfg(feature = "std")]
 //!     calendar.save_file("birthday.ics")?;
 //!     Ok(())
 //! }
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![forbid(unsafe_code, missing_docs)]
 
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 pub mod components;
-mod contentline;
+pub mod contentline;
+mod diff;
+mod error;
+pub mod expand;
 mod ical;
 pub mod parameters;
+mod parser;
+pub mod prelude;
 pub mod properties;
+#[cfg(feature = "tz")]
+mod tz;
 mod util;
+mod validation;
+pub mod values;
+#[cfg(feature = "std")]
+mod writer;
 
+pub use diff::CalendarDiff;
+pub use diff::EventDiff;
+pub use diff::PropertyChange;
 pub use ical::Alarm;
+pub use ical::Availability;
+pub use ical::Available;
+pub use ical::CalendarComponent;
 pub use ical::Daylight;
 pub use ical::Event;
 pub use ical::FreeBusy;
 pub use ical::ICalendar;
+pub use ical::ICalendarBuilder;
 pub use ical::Journal;
+pub use ical::Participant;
+pub use ical::Poll;
 pub use ical::Standard;
+pub use ical::StructuredLocation;
 pub use ical::TimeZone;
 pub use ical::ToDo;
+pub use ical::WriteOptions;
+
+pub use error::Error;
+#[cfg(feature = "std")]
+pub use parser::parse_with_handler;
+pub use parser::ComponentHandler;
+pub use parser::ParseError;
+pub use validation::ValidationError;
+#[cfg(feature = "std")]
+pub use writer::CalendarWriter;
 
 pub use util::escape_text;
+pub use util::normalize_text;
+pub use util::unescape_text;
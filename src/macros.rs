@@ -19,9 +19,8 @@
 macro_rules! parameters {
     ($($key:expr => $value:expr);*) => {
         {
-            use std::collections::BTreeMap;
             use $crate::components::Parameters;
-            let mut parameters: Parameters = BTreeMap::new();
+            let mut parameters = Parameters::new();
             $(
                 parameters.insert($key.into(), $value.into());
             )*
@@ -30,21 +29,6 @@ macro_rules! parameters {
     };
 }
 
-#[cfg(test)]
-mod test {
-    use crate::components::Parameters;
-    use std::collections::BTreeMap;
-
-    #[test]
-    fn parameters() {
-        let mut b_map: Parameters = BTreeMap::new();
-        b_map.insert("VALUE".into(), "BOOLEAN".into());
-        b_map.insert("CUTYPE".into(), "GROUP".into());
-        let param = parameters!("VALUE" => "BOOLEAN"; "CUTYPE" => "GROUP");
-        assert_eq!(b_map, param);
-    }
-}
-
 // Creation and conversion from builder types to Property
 macro_rules! property {
     ($type:ident, $name:expr) => {
@@ -132,9 +116,12 @@ macro_rules! parameter {
             where
                 S: Into<Cow<'a, str>>,
             {
-                Self {
-                    value: value.into(),
-                }
+                let value = value.into();
+                debug_assert!(
+                    crate::components::is_valid_parameter_value($name, &value),
+                    "parameter value must not contain a double quote"
+                );
+                Self { value }
             }
         }
 
@@ -182,5 +169,125 @@ macro_rules! impl_from_prop {
                 }
             }
         }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $type<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let property = Property {
+                    key: Cow::Borrowed($name),
+                    value: Cow::Borrowed(&self.value),
+                    parameters: self.parameters.clone(),
+                };
+                serde::Serialize::serialize(&property, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, 'a> serde::Deserialize<'de> for $type<'a> {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let property: Property = serde::Deserialize::deserialize(deserializer)?;
+                if !property.key.eq_ignore_ascii_case($name) {
+                    return Err(serde::de::Error::custom(format_args!(
+                        "expected {} property, found {}",
+                        $name, property.key
+                    )));
+                }
+                Ok(Self {
+                    value: property.value,
+                    parameters: property.parameters,
+                })
+            }
+        }
+    };
+}
+
+// Constructor for date-time properties from a UTC date-time
+macro_rules! impl_from_datetime {
+    ($type:ident, $name:expr) => {
+        impl $type<'_> {
+            #[doc = "Creates a new `"]
+            #[doc=$name]
+            #[doc = "` Property from a UTC date-time. It is written in the basic format like `19960918T143000Z`."]
+            pub fn from_datetime<D>(datetime: D) -> Self
+            where
+                D: Into<values::DateTime>,
+            {
+                Self::new(datetime.into().to_string())
+            }
+
+            #[doc = "Creates a new `"]
+            #[doc=$name]
+            #[doc = "` Property from a date-time of the `time` crate. It is converted to UTC."]
+            #[cfg(feature = "time")]
+            pub fn from_offset_datetime(datetime: time::OffsetDateTime) -> Self {
+                Self::from_datetime(datetime)
+            }
+        }
     };
 }
+
+// Constructor for properties which can also be a date
+macro_rules! impl_from_date {
+    ($type:ident, $name:expr) => {
+        impl<'a> $type<'a> {
+            #[doc = "Creates a new `"]
+            #[doc=$name]
+            #[doc = "` Property from a date in the basic format like `19970714`. It contains `VALUE=DATE`, so it is not mistaken for a date-time."]
+            pub fn date<S>(date: S) -> Self
+            where
+                S: Into<Cow<'a, str>>,
+            {
+                let mut property = Self::new(date);
+                property.add(crate::parameters::Value::DATE);
+                property
+            }
+
+            #[doc = "Creates a new `"]
+            #[doc=$name]
+            #[doc = "` Property from a date. It is written in the basic format like `19970714` and contains `VALUE=DATE`."]
+            pub fn from_date<D>(date: D) -> Self
+            where
+                D: Into<values::Date>,
+            {
+                Self::date(date.into().to_string())
+            }
+        }
+    };
+}
+
+macro_rules! impl_text {
+    ($type:ident, $name:expr) => {
+        impl<'a> $type<'a> {
+            #[doc = "Creates a new `"]
+            #[doc=$name]
+            #[doc = "` Property from unescaped text. Commas, semicolons, backslashes and line breaks are escaped, unlike with `new()` which takes the value as it is written."]
+            pub fn text<S>(text: S) -> Self
+            where
+                S: Into<Cow<'a, str>>,
+            {
+                Self::new(escape_text(text))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::components::Parameters;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn parameters() {
+        let mut b_map: Parameters = BTreeMap::new();
+        b_map.insert("VALUE".into(), "BOOLEAN".into());
+        b_map.insert("CUTYPE".into(), "GROUP".into());
+        let param = parameters!("VALUE" => "BOOLEAN"; "CUTYPE" => "GROUP");
+        assert_eq!(b_map, param);
+    }
+}
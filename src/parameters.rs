@@ -17,9 +17,29 @@
 //! assert_eq!(CUType::new("INDIVIDUAL"), individual);
 //! assert_eq!(Parameter::new("CUTYPE", "INDIVIDUAL"), individual.into());
 //! ```
+//!
+//! Parameters with a fixed set of values like [`RSVP`] are enums. Parameters
+//! which also allow IANA registered or experimental values like [`PartStat`],
+//! [`Role`] or [`CUType`] have associated constants for the specified values
+//! instead. Misspelled constants are compile errors and other values can still
+//! be created with the generic constructor.
+//!
+//! ```
+//! use ics::parameters::{CUType, PartStat, Role};
+//! use ics::properties::Attendee;
+//!
+//! let mut attendee = Attendee::new("mailto:jsmith@example.com");
+//! attendee.add(Role::CHAIR);
+//! attendee.add(PartStat::ACCEPTED);
+//! attendee.add(CUType::new("X-VIRTUAL"));
+//! ```
 //! For more information on parameters, please refer to the specification [RFC5545 3.2. Property Parameters](https://tools.ietf.org/html/rfc5545#section-3.2) and [RFC7986 6. Property Parameters](https://tools.ietf.org/html/rfc7986#section-6).
 use crate::components::Parameter;
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
 
 parameter!(AltRep, "ALTREP");
 parameter!(CN, "CN");
@@ -136,7 +156,7 @@ impl RelType<'_> {
     };
 
     /// Specifies a sibling relationship.
-    const SIBLING: Self = Self {
+    pub const SIBLING: Self = Self {
         value: Cow::Borrowed("SIBLING"),
     };
 
@@ -269,6 +289,68 @@ impl Default for Role<'_> {
     }
 }
 
+/// Creates a new `DELEGATED-FROM` Parameter from a list of calendar user
+/// addresses. Each address is quoted as the specification requires. An
+/// address must not contain a double quote, which is checked in debug
+/// builds.
+impl<'a, S> FromIterator<S> for DelegatedFrom<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(addresses: I) -> Self {
+        Self::new(quoted_list(addresses))
+    }
+}
+
+/// Creates a new `DELEGATED-TO` Parameter from a list of calendar user
+/// addresses. Each address is quoted as the specification requires. An
+/// address must not contain a double quote, which is checked in debug
+/// builds.
+///
+/// # Example
+/// ```
+/// use ics::parameters::DelegatedTo;
+///
+/// let delegated_to: DelegatedTo = vec!["mailto:a@x.com", "mailto:b@x.com"].into_iter().collect();
+/// assert_eq!(
+///     delegated_to,
+///     DelegatedTo::new("\"mailto:a@x.com\",\"mailto:b@x.com\"")
+/// );
+/// ```
+impl<'a, S> FromIterator<S> for DelegatedTo<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(addresses: I) -> Self {
+        Self::new(quoted_list(addresses))
+    }
+}
+
+/// Creates a new `MEMBER` Parameter from a list of calendar user addresses of
+/// groups. Each address is quoted as the specification requires. An
+/// address must not contain a double quote, which is checked in debug
+/// builds.
+impl<'a, S> FromIterator<S> for Member<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(addresses: I) -> Self {
+        Self::new(quoted_list(addresses))
+    }
+}
+
+fn quoted_list<'a, S, I>(addresses: I) -> String
+where
+    S: Into<Cow<'a, str>>,
+    I: IntoIterator<Item = S>,
+{
+    let addresses: Vec<String> = addresses
+        .into_iter()
+        .map(|address| format!("\"{}\"", address.into()))
+        .collect();
+    addresses.join(",")
+}
+
 /// `ENCODING` Parameter
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Encoding {
@@ -380,7 +462,7 @@ pub use self::rfc7986::*;
 #[cfg(feature = "rfc7986")]
 mod rfc7986 {
     use crate::components::Parameter;
-    use std::borrow::Cow;
+    use alloc::borrow::Cow;
 
     parameter!(Display, "DISPLAY");
     parameter!(Email, "EMAIL");
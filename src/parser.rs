@@ -0,0 +1,456 @@
+//! Reading iCalendar objects from their text representation.
+use crate::components::{Component, Parameters, Property};
+use crate::ical::{CalendarComponent, ICalendar};
+use alloc::borrow::{Cow, ToOwned};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io::{self, BufRead, BufReader, Read};
+
+/// The error type for parsing iCalendar objects. It contains the line number
+/// where the content line of the failure starts.
+#[derive(Debug)]
+pub struct ParseError {
+    line: usize,
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+pub(crate) enum ErrorKind {
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    InvalidUtf8,
+    MissingValue,
+    InvalidParameter,
+    MissingCalendar,
+    UnexpectedEnd(String),
+    UnclosedComponent(String),
+    ContentAfterCalendar,
+    ContentAfterProperty,
+}
+
+impl ParseError {
+    fn new(line: usize, kind: ErrorKind) -> Self {
+        ParseError { line, kind }
+    }
+
+    /// Returns the line number where the content line of the error starts.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: ", self.line)?;
+        match &self.kind {
+            #[cfg(feature = "std")]
+            ErrorKind::Io(error) => write!(f, "{}", error),
+            ErrorKind::InvalidUtf8 => write!(f, "content line is not valid UTF-8"),
+            ErrorKind::MissingValue => write!(f, "content line has no `:` separating the value"),
+            ErrorKind::InvalidParameter => write!(f, "parameter has no `=` separating the value"),
+            ErrorKind::MissingCalendar => write!(f, "expected `BEGIN:VCALENDAR`"),
+            ErrorKind::UnexpectedEnd(name) => write!(f, "unexpected `END:{}`", name),
+            ErrorKind::UnclosedComponent(name) => write!(f, "missing `END:{}`", name),
+            ErrorKind::ContentAfterCalendar => {
+                write!(f, "unexpected content after `END:VCALENDAR`")
+            }
+            ErrorKind::ContentAfterProperty => write!(f, "expected a single content line"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// Source of the physical lines of the input. Readers are only available with
+// the standard library, otherwise only byte slices can be parsed.
+pub(crate) trait ReadLine {
+    // Appends the next line including the line break to the buffer and
+    // returns the number of bytes read.
+    fn read_line(&mut self, buffer: &mut Vec<u8>) -> Result<usize, ErrorKind>;
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> ReadLine for R {
+    fn read_line(&mut self, buffer: &mut Vec<u8>) -> Result<usize, ErrorKind> {
+        self.read_until(b'\n', buffer).map_err(ErrorKind::Io)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl ReadLine for &[u8] {
+    fn read_line(&mut self, buffer: &mut Vec<u8>) -> Result<usize, ErrorKind> {
+        let end = self
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .map_or(self.len(), |index| index + 1);
+        buffer.extend_from_slice(&self[..end]);
+        *self = &self[end..];
+        Ok(end)
+    }
+}
+
+// Reads unfolded content lines together with the number of the physical line
+// where they start. Folds are removed on the raw bytes because a fold can
+// split a UTF-8 character.
+struct ContentLines<R> {
+    reader: R,
+    line: usize,
+    peeked: Option<Vec<u8>>,
+}
+
+impl<R: ReadLine> ContentLines<R> {
+    fn new(reader: R) -> Self {
+        ContentLines {
+            reader,
+            line: 0,
+            peeked: None,
+        }
+    }
+
+    fn read_physical_line(&mut self) -> Result<Option<Vec<u8>>, ParseError> {
+        let mut buffer = Vec::new();
+        let read = self
+            .reader
+            .read_line(&mut buffer)
+            .map_err(|kind| ParseError::new(self.line + 1, kind))?;
+        if read == 0 {
+            return Ok(None);
+        }
+        if buffer.last() == Some(&b'\n') {
+            buffer.pop();
+        }
+        if buffer.last() == Some(&b'\r') {
+            buffer.pop();
+        }
+        Ok(Some(buffer))
+    }
+
+    fn next_line(&mut self) -> Result<Option<(usize, String)>, ParseError> {
+        let mut content = match self.peeked.take() {
+            Some(content) => content,
+            None => match self.read_physical_line()? {
+                Some(content) => {
+                    self.line += 1;
+                    content
+                }
+                None => return Ok(None),
+            },
+        };
+        let start = self.line;
+
+        while let Some(next) = self.read_physical_line()? {
+            self.line += 1;
+            match next.first() {
+                Some(b' ') | Some(b'\t') => content.extend_from_slice(&next[1..]),
+                _ => {
+                    self.peeked = Some(next);
+                    break;
+                }
+            }
+        }
+
+        match String::from_utf8(content) {
+            Ok(content) => Ok(Some((start, content))),
+            Err(_) => Err(ParseError::new(start, ErrorKind::InvalidUtf8)),
+        }
+    }
+}
+
+// Splits an unfolded content line into name, parameters and value. Colons and
+// semicolons in quoted parameter values do not separate anything.
+pub(crate) fn parse_content_line(line: &str) -> Result<Property<'static>, LineError> {
+    let mut in_quotes = false;
+    let mut separators = Vec::new();
+    let mut value_start = None;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => separators.push(index),
+            ':' if !in_quotes => {
+                value_start = Some(index);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let value_start = value_start.ok_or(LineError::MissingValue)?;
+    let name_end = separators.first().cloned().unwrap_or(value_start);
+
+    let mut parameters = Parameters::new();
+    separators.push(value_start);
+    for bounds in separators.windows(2) {
+        let parameter = &line[bounds[0] + 1..bounds[1]];
+        let equals = parameter.find('=').ok_or(LineError::InvalidParameter)?;
+        parameters.insert(
+            Cow::Owned(parameter[..equals].to_owned()),
+            Cow::Owned(parameter[equals + 1..].to_owned()),
+        );
+    }
+
+    Ok(Property {
+        key: Cow::Owned(line[..name_end].to_owned()),
+        value: Cow::Owned(line[value_start + 1..].to_owned()),
+        parameters,
+    })
+}
+
+// Errors of a single content line which do not know their line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LineError {
+    MissingValue,
+    InvalidParameter,
+}
+
+impl LineError {
+    fn at(self, line: usize) -> ParseError {
+        let kind = match self {
+            LineError::MissingValue => ErrorKind::MissingValue,
+            LineError::InvalidParameter => ErrorKind::InvalidParameter,
+        };
+        ParseError::new(line, kind)
+    }
+}
+
+pub(crate) fn read_property(input: &str) -> Result<Property<'static>, ParseError> {
+    let mut lines = ContentLines::new(input.as_bytes());
+    let (number, line) = lines.next_line()?.unwrap_or((0, String::new()));
+    let property = parse_content_line(&line).map_err(|error| error.at(number))?;
+    // Only empty lines may follow the content line.
+    while let Some((number, line)) = lines.next_line()? {
+        if !line.is_empty() {
+            return Err(ParseError::new(number, ErrorKind::ContentAfterProperty));
+        }
+    }
+    Ok(property)
+}
+
+/// A handler for the parts of an iCalendar object that
+/// [`parse_with_handler()`](crate::parse_with_handler) passes to it as soon as
+/// they are parsed.
+///
+/// Closures taking a [`CalendarComponent`] implement this trait and ignore the
+/// properties of the calendar.
+pub trait ComponentHandler {
+    /// Handles a top-level component like an event once its `END` line is
+    /// parsed. Its subcomponents are part of it.
+    fn component(&mut self, component: CalendarComponent<'static>);
+
+    /// Handles a property of the calendar itself like `VERSION`. They are
+    /// ignored by default.
+    fn property(&mut self, property: Property<'static>) {
+        let _ = property;
+    }
+}
+
+impl<F> ComponentHandler for F
+where
+    F: FnMut(CalendarComponent<'static>),
+{
+    fn component(&mut self, component: CalendarComponent<'static>) {
+        self(component)
+    }
+}
+
+// Collects everything for the parse functions of `ICalendar`
+struct Collector(ICalendar<'static>);
+
+impl ComponentHandler for Collector {
+    fn component(&mut self, component: CalendarComponent<'static>) {
+        self.0.components.push(component);
+    }
+
+    fn property(&mut self, property: Property<'static>) {
+        self.0.properties.push(property);
+    }
+}
+
+/// Reads and parses an iCalendar object from a reader like a file and passes
+/// its properties and top-level components to the handler one by one instead
+/// of collecting them in an [`ICalendar`]. Only the component that is being
+/// parsed is kept in memory, so large feeds can be processed in constant
+/// memory. See [`ICalendar::parse()`] for more information on parsing.
+///
+/// Components are handled before the rest of the input is read, so the
+/// handler may already have seen some components when an error is returned.
+///
+/// # Example
+/// ```
+/// use ics::{parse_with_handler, CalendarComponent};
+///
+/// let input = "BEGIN:VCALENDAR\r\n\
+///              VERSION:2.0\r\n\
+///              PRODID:ics-rs\r\n\
+///              BEGIN:VEVENT\r\n\
+///              UID:1\r\n\
+///              DTSTAMP:19960704T120000Z\r\n\
+///              END:VEVENT\r\n\
+///              END:VCALENDAR\r\n";
+///
+/// let mut uids = Vec::new();
+/// parse_with_handler(input.as_bytes(), &mut |component| {
+///     if let CalendarComponent::Event(event) = component {
+///         uids.push(event.get("UID").unwrap().value().to_owned());
+///     }
+/// })
+/// .unwrap();
+/// assert_eq!(uids, ["1"]);
+/// ```
+#[cfg(feature = "std")]
+pub fn parse_with_handler<R, H>(reader: R, handler: &mut H) -> Result<(), ParseError>
+where
+    R: Read,
+    H: ComponentHandler + ?Sized,
+{
+    read_components(BufReader::new(reader), handler)
+}
+
+pub(crate) fn read_calendar<R: ReadLine>(reader: R) -> Result<ICalendar<'static>, ParseError> {
+    let mut collector = Collector(ICalendar {
+        properties: Vec::new(),
+        components: Vec::new(),
+    });
+    read_components(reader, &mut collector)?;
+    Ok(collector.0)
+}
+
+fn read_components<R, H>(reader: R, handler: &mut H) -> Result<(), ParseError>
+where
+    R: ReadLine,
+    H: ComponentHandler + ?Sized,
+{
+    let mut lines = ContentLines::new(reader);
+    // Components that have begun but not yet ended
+    let mut open: Vec<Component<'static>> = Vec::new();
+    let mut started = false;
+
+    while let Some((number, line)) = lines.next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+        // A byte order mark is only allowed at the beginning of the input.
+        let line = if number == 1 && line.starts_with('\u{feff}') {
+            &line['\u{feff}'.len_utf8()..]
+        } else {
+            &line
+        };
+        let property = parse_content_line(line).map_err(|e| e.at(number))?;
+
+        if !started {
+            if property.key.eq_ignore_ascii_case("BEGIN")
+                && property.value.eq_ignore_ascii_case("VCALENDAR")
+            {
+                started = true;
+                continue;
+            }
+            return Err(ParseError::new(number, ErrorKind::MissingCalendar));
+        }
+
+        if property.key.eq_ignore_ascii_case("BEGIN") {
+            open.push(Component::new(property.value));
+        } else if property.key.eq_ignore_ascii_case("END") {
+            match open.pop() {
+                Some(component) => {
+                    if !component.name.eq_ignore_ascii_case(&property.value) {
+                        let kind = ErrorKind::UnexpectedEnd(property.value.into_owned());
+                        return Err(ParseError::new(number, kind));
+                    }
+                    match open.last_mut() {
+                        Some(parent) => parent.subcomponents.push(component),
+                        None => handler.component(CalendarComponent::from_component(component)),
+                    }
+                }
+                None if property.value.eq_ignore_ascii_case("VCALENDAR") => {
+                    return finish(lines);
+                }
+                None => {
+                    let kind = ErrorKind::UnexpectedEnd(property.value.into_owned());
+                    return Err(ParseError::new(number, kind));
+                }
+            }
+        } else {
+            match open.last_mut() {
+                Some(component) => component.properties.push(property),
+                None => handler.property(property),
+            }
+        }
+    }
+
+    let name = match open.pop() {
+        Some(component) => component.name.into_owned(),
+        None if started => "VCALENDAR".to_owned(),
+        None => return Err(ParseError::new(lines.line, ErrorKind::MissingCalendar)),
+    };
+    Err(ParseError::new(
+        lines.line,
+        ErrorKind::UnclosedComponent(name),
+    ))
+}
+
+// Only empty lines may follow the end of the calendar.
+fn finish<R: ReadLine>(mut lines: ContentLines<R>) -> Result<(), ParseError> {
+    while let Some((number, line)) = lines.next_line()? {
+        if !line.is_empty() {
+            return Err(ParseError::new(number, ErrorKind::ContentAfterCalendar));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_content_line, LineError};
+    use crate::components::Property;
+    use crate::ICalendar;
+
+    #[test]
+    fn content_line() {
+        let mut expected = Property::new("DTSTART", "20180906");
+        expected.append(parameters!("TZID" => "America/New_York"; "VALUE" => "DATE"));
+        let property = parse_content_line("DTSTART;TZID=America/New_York;VALUE=DATE:20180906");
+        assert_eq!(property, Ok(expected));
+    }
+
+    #[test]
+    fn quoted_parameter() {
+        let mut expected = Property::new("ATTENDEE", "mailto:jsmith@example.com");
+        expected.append(parameters!("DELEGATED-TO" => "\"mailto:a@x.com\",\"mailto:b;c@x.com\""));
+        let property = parse_content_line(
+            "ATTENDEE;DELEGATED-TO=\"mailto:a@x.com\",\"mailto:b;c@x.com\":mailto:jsmith@example.com",
+        );
+        assert_eq!(property, Ok(expected));
+    }
+
+    #[test]
+    fn invalid_content_lines() {
+        assert_eq!(parse_content_line("SUMMARY"), Err(LineError::MissingValue));
+        assert_eq!(
+            parse_content_line("SUMMARY;LANGUAGE:Text"),
+            Err(LineError::InvalidParameter)
+        );
+    }
+
+    #[test]
+    fn error_line() {
+        let input = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nSUMMARY:A long\r\n  summary\r\nUID\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let error = ICalendar::parse(input).unwrap_err();
+        assert_eq!(error.line(), 6);
+    }
+
+    #[test]
+    fn unclosed_component() {
+        let input = "BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:1\r\nEND:VCALENDAR\r\n";
+        let error = ICalendar::parse(input).unwrap_err();
+        assert_eq!(error.line(), 4);
+        assert_eq!(error.to_string(), "line 4: unexpected `END:VCALENDAR`");
+    }
+}
@@ -0,0 +1,29 @@
+//! Re-exports of the most commonly used types.
+//!
+//! The types can still be imported from their modules. Properties and
+//! parameters that are used less often have to be imported from
+//! [`properties`](crate::properties) and [`parameters`](mod@crate::parameters).
+//!
+//! # Example
+//! ```
+//! use ics::prelude::*;
+//!
+//! let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+//! event.push(DtStart::new("19960918T143000Z"));
+//! event.push(Summary::new("Networld+Interop Conference"));
+//! event.push(Description::new(escape_text("Atlanta, Georgia")));
+//!
+//! let mut calendar = ICalendar::new("2.0", "ics-rs");
+//! calendar.add_event(event);
+//! ```
+pub use crate::components::{Component, Parameter, Property};
+pub use crate::parameters::{PartStat, Role, TzIDParam, Value};
+#[cfg(feature = "rfc7986")]
+pub use crate::properties::Email;
+pub use crate::properties::{
+    Attendee, Categories, Class, Description, DtEnd, DtStart, Due, Duration, Location, Organizer,
+    RRule, Status, Summary, Trigger, UID, URL,
+};
+pub use crate::{
+    escape_text, Alarm, Daylight, Event, FreeBusy, ICalendar, Journal, Standard, TimeZone, ToDo,
+};
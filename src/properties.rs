@@ -22,8 +22,20 @@
 //! ```
 //! For more information on properties, please refer to the specification [RFC5545 3.7. Calendar Properties](https://tools.ietf.org/html/rfc5545#section-3.7) and [RFC7986 5. Properties](https://tools.ietf.org/html/rfc7986#section-5).
 use crate::components::{Parameter, Parameters, Property};
-use std::borrow::Cow;
-use std::collections::BTreeMap;
+use crate::parameters::{
+    AltRep, DelegatedTo, Dir, FBType, PartStat, Range, RelType, Related, Role, SentBy, Value, CN,
+    RSVP,
+};
+use crate::util::{encode_base64, escape_text};
+use crate::validation::date_value_type;
+use crate::values;
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FromIterator;
 
 property!(CalScale, "CALSCALE");
 property!(Method, "METHOD");
@@ -47,6 +59,7 @@ property!(Due, "DUE");
 property!(DtStart, "DTSTART");
 property!(Duration, "DURATION");
 property!(FreeBusyTime, "FREEBUSY");
+property!(BusyType, "BUSYTYPE");
 property!(Transp, "TRANSP");
 property!(TzID, "TZID");
 property!(TzName, "TZNAME");
@@ -71,6 +84,19 @@ property!(DtStamp, "DTSTAMP");
 property!(LastModified, "LAST-MODIFIED");
 property!(Sequence, "SEQUENCE");
 property!(RequestStatus, "REQUEST-STATUS");
+// Properties of structured event data (RFC9073)
+property!(ParticipantType, "PARTICIPANT-TYPE");
+property!(CalendarAddress, "CALENDAR-ADDRESS");
+property!(LocationType, "LOCATION-TYPE");
+property!(StructuredData, "STRUCTURED-DATA");
+// Properties of consensus scheduling (VPOLL draft, experimental)
+property!(PollMode, "POLL-MODE");
+property!(PollProperties, "POLL-PROPERTIES");
+property!(PollItemID, "POLL-ITEM-ID");
+// Widely supported non-standard properties
+property!(XWrCalName, "X-WR-CALNAME");
+property!(XWrCalDesc, "X-WR-CALDESC");
+property!(XWrTimeZone, "X-WR-TIMEZONE");
 
 impl Class<'_> {
     /// Specifies the access classification as public for a component (default value).
@@ -89,6 +115,49 @@ impl Class<'_> {
     }
 }
 
+impl Method<'_> {
+    /// Method for publishing a calendar without a reply, e.g. a feed.
+    pub fn publish() -> Self {
+        Self::new("PUBLISH")
+    }
+
+    /// Method for scheduling a component with the attendees.
+    pub fn request() -> Self {
+        Self::new("REQUEST")
+    }
+
+    /// Method for the reply of an attendee to a request.
+    pub fn reply() -> Self {
+        Self::new("REPLY")
+    }
+
+    /// Method `ADD` for adding instances to a recurring component. It is not
+    /// called `add()` because that method adds parameters.
+    pub fn add_instances() -> Self {
+        Self::new("ADD")
+    }
+
+    /// Method for cancelling a component or instances of it.
+    pub fn cancel() -> Self {
+        Self::new("CANCEL")
+    }
+
+    /// Method for an attendee to request the latest version of a component.
+    pub fn refresh() -> Self {
+        Self::new("REFRESH")
+    }
+
+    /// Method for an attendee to propose changes to a component.
+    pub fn counter() -> Self {
+        Self::new("COUNTER")
+    }
+
+    /// Method for the organizer to decline a counter proposal.
+    pub fn decline_counter() -> Self {
+        Self::new("DECLINECOUNTER")
+    }
+}
+
 impl Status<'_> {
     /// Status for a tentative event.
     pub fn tentative() -> Self {
@@ -131,6 +200,75 @@ impl Status<'_> {
     }
 }
 
+impl BusyType<'_> {
+    /// The time is busy.
+    pub fn busy() -> Self {
+        Self::new("BUSY")
+    }
+
+    /// The time is unavailable (default value).
+    pub fn busy_unavailable() -> Self {
+        Self::new("BUSY-UNAVAILABLE")
+    }
+
+    /// The time is tentatively busy.
+    pub fn busy_tentative() -> Self {
+        Self::new("BUSY-TENTATIVE")
+    }
+}
+
+impl ParticipantType<'_> {
+    /// The participant takes part in the event.
+    pub fn active() -> Self {
+        Self::new("ACTIVE")
+    }
+
+    /// The participant does not take part in the event, e.g. an observer.
+    pub fn inactive() -> Self {
+        Self::new("INACTIVE")
+    }
+
+    /// The participant sponsors the event.
+    pub fn sponsor() -> Self {
+        Self::new("SPONSOR")
+    }
+
+    /// The participant is a contact for the event.
+    pub fn contact() -> Self {
+        Self::new("CONTACT")
+    }
+
+    /// The participant is the contact for booking the event.
+    pub fn booking_contact() -> Self {
+        Self::new("BOOKING-CONTACT")
+    }
+
+    /// The participant is the contact for emergencies.
+    pub fn emergency_contact() -> Self {
+        Self::new("EMERGENCY-CONTACT")
+    }
+
+    /// The participant is the contact for publicity.
+    pub fn publicity_contact() -> Self {
+        Self::new("PUBLICITY-CONTACT")
+    }
+
+    /// The participant is the contact for the planning of the event.
+    pub fn planner_contact() -> Self {
+        Self::new("PLANNER-CONTACT")
+    }
+
+    /// The participant performs at the event.
+    pub fn performer() -> Self {
+        Self::new("PERFORMER")
+    }
+
+    /// The participant speaks at the event.
+    pub fn speaker() -> Self {
+        Self::new("SPEAKER")
+    }
+}
+
 impl Transp<'_> {
     /// Blocks or opaque on busy time searches (default value).
     pub fn opaque() -> Self {
@@ -143,6 +281,514 @@ impl Transp<'_> {
     }
 }
 
+#[cfg(feature = "uuid")]
+impl UID<'_> {
+    /// Creates a new `UID` property from a random (version 4) UUID which is a
+    /// globally unique identifier as recommended by RFC7986.
+    pub fn random() -> Self {
+        Self::new(random_uid())
+    }
+
+    /// Creates a new `UID` property from a random (version 4) UUID that is
+    /// qualified with a domain like `uuid@example.com`.
+    pub fn random_with_domain(domain: &str) -> Self {
+        Self::new(format!("{}@{}", random_uid(), domain))
+    }
+}
+
+// Generates the value of a random `UID`, which is also used by the
+// constructors of the components.
+#[cfg(feature = "uuid")]
+pub(crate) fn random_uid() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+impl Geo<'_> {
+    /// Creates a new `GEO` Property from latitude and longitude. Both values
+    /// are formatted with six decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::Geo;
+    ///
+    /// assert_eq!(Geo::from_coords(37.386013, -122.082932), Geo::new("37.386013;-122.082932"));
+    /// ```
+    pub fn from_coords(latitude: f64, longitude: f64) -> Self {
+        debug_assert!(
+            (-90.0..=90.0).contains(&latitude),
+            "latitude must be between -90 and 90 degrees"
+        );
+        debug_assert!(
+            (-180.0..=180.0).contains(&longitude),
+            "longitude must be between -180 and 180 degrees"
+        );
+        Self::new(format!("{:.6};{:.6}", latitude, longitude))
+    }
+}
+
+/// Creates a new `CATEGORIES` Property from a list of categories. Each
+/// category is escaped, so a comma inside of it does not separate it.
+///
+/// # Example
+/// ```
+/// use ics::properties::Categories;
+///
+/// let categories: Categories = vec!["WORK", "MEETING"].into_iter().collect();
+/// assert_eq!(categories, Categories::new("WORK,MEETING"));
+/// ```
+impl<'a, S> FromIterator<S> for Categories<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(categories: I) -> Self {
+        Self::new(join_text_list(categories))
+    }
+}
+
+/// Creates a new `RESOURCES` Property from a list of resources. Each resource
+/// is escaped, so a comma inside of it does not separate it.
+impl<'a, S> FromIterator<S> for Resources<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(resources: I) -> Self {
+        Self::new(join_text_list(resources))
+    }
+}
+
+/// Creates a new `FREEBUSY` Property from a list of periods like
+/// `19970308T160000Z/PT8H30M`.
+impl<'a, S> FromIterator<S> for FreeBusyTime<'a>
+where
+    S: Into<Cow<'a, str>>,
+{
+    fn from_iter<I: IntoIterator<Item = S>>(periods: I) -> Self {
+        Self::new(join_list(periods.into_iter().map(Into::into)))
+    }
+}
+
+impl<'a> FreeBusyTime<'a> {
+    /// Creates a new `FREEBUSY` Property from a list of periods with the
+    /// `FBTYPE` parameter.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::parameters::FBType;
+    /// use ics::properties::FreeBusyTime;
+    /// use ics::values::{Date, DateTime, Duration, Period};
+    ///
+    /// let start = DateTime::new(Date::new(1997, 3, 8), 16, 0, 0);
+    /// let period = Period::start_duration(start, Duration::new().hours(3));
+    /// let mut expected = FreeBusyTime::new("19970308T160000Z/PT3H");
+    /// expected.add(FBType::BUSY);
+    /// assert_eq!(FreeBusyTime::from_periods(&[period], FBType::BUSY), expected);
+    /// ```
+    pub fn from_periods(periods: &[values::Period], fbtype: FBType<'a>) -> Self {
+        let mut freebusy: Self = periods.iter().map(ToString::to_string).collect();
+        freebusy.add(fbtype);
+        freebusy
+    }
+}
+
+// Multiple TEXT values are escaped and separated by unescaped commas.
+fn join_text_list<'a, I, S>(values: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Into<Cow<'a, str>>,
+{
+    join_list(values.into_iter().map(escape_text))
+}
+
+// Joins the values of a property with multiple values. An empty list results
+// in an empty value.
+fn join_list<'a, I>(values: I) -> String
+where
+    I: Iterator<Item = Cow<'a, str>>,
+{
+    let values: Vec<_> = values.collect();
+    values.join(",")
+}
+
+impl<'a> Attach<'a> {
+    /// Creates a new `ATTACH` Property which refers to the attachment by a URI.
+    pub fn uri<S>(uri: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::new(uri)
+    }
+
+    /// Creates a new `ATTACH` Property which contains the data of the
+    /// attachment with the media type like `application/pdf`. The data is
+    /// encoded as base64 and the parameters `ENCODING=BASE64` and
+    /// `VALUE=BINARY` are added.
+    pub fn binary<S>(data: &[u8], fmttype: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Attach {
+            value: Cow::Owned(encode_base64(data)),
+            parameters: parameters!("ENCODING" => "BASE64"; "FMTTYPE" => fmttype; "VALUE" => "BINARY"),
+        }
+    }
+}
+
+impl RequestStatus<'_> {
+    /// Creates a new `REQUEST-STATUS` Property from the status code like
+    /// `2.0`, the description and the optional extra data. Each part is
+    /// escaped as text and the parts are separated by semicolons. It is not
+    /// called `new()` because that constructor takes the whole value.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::RequestStatus;
+    ///
+    /// let status = RequestStatus::from_parts("2.0", "Success", None);
+    /// assert_eq!(status, RequestStatus::new("2.0;Success"));
+    /// ```
+    pub fn from_parts(code: &str, description: &str, extra: Option<&str>) -> Self {
+        let mut value = format!("{};{}", escape_text(code), escape_text(description));
+        if let Some(extra) = extra {
+            value.push(';');
+            value.push_str(&escape_text(extra));
+        }
+        Self::new(value)
+    }
+}
+
+impl<'a> Contact<'a> {
+    /// Sets the `ALTREP` parameter to the URI of an alternate representation
+    /// of the contact like a vCard or a directory entry and returns the
+    /// property. The URI is always quoted.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    /// use ics::properties::Contact;
+    ///
+    /// let contact = Contact::text("Jim Dolittle").alt_rep("http://example.com/pdi/jdoe.vcf");
+    /// assert_eq!(
+    ///     Property::from(contact).to_string(),
+    ///     "CONTACT;ALTREP=\"http://example.com/pdi/jdoe.vcf\":Jim Dolittle\r\n"
+    /// );
+    /// ```
+    pub fn alt_rep<S>(mut self, uri: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.add(AltRep::new(uri));
+        self
+    }
+}
+
+impl<'a> RelatedTo<'a> {
+    /// Sets the `RELTYPE` parameter and returns the property. Without it the
+    /// relationship is interpreted as [`RelType::PARENT`].
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    /// use ics::parameters::RelType;
+    /// use ics::properties::RelatedTo;
+    ///
+    /// let related_to = RelatedTo::new("jsmith.part7.19960817T083000.xyzMail@example.com")
+    ///     .rel_type(RelType::CHILD);
+    /// assert_eq!(
+    ///     Property::from(related_to).to_string(),
+    ///     "RELATED-TO;RELTYPE=CHILD:jsmith.part7.19960817T083000.xyzMail@example.com\r\n"
+    /// );
+    /// ```
+    pub fn rel_type(mut self, rel_type: RelType<'a>) -> Self {
+        self.add(rel_type);
+        self
+    }
+}
+
+impl<'a> URL<'a> {
+    /// Creates a new `URL` Property after checking that the value starts with
+    /// a URI scheme like `https:` or `cid:`. Other than that the URI is not
+    /// validated. URIs are not text, so the value must not be escaped with
+    /// [`escape_text`].
+    ///
+    /// # Example
+    /// ```
+    /// use ics::properties::URL;
+    ///
+    /// assert!(URL::parse("https://example.com/calendar?a=1,2").is_ok());
+    /// assert!(URL::parse("cid:part1.0001@example.org").is_ok());
+    /// assert!(URL::parse("example.com/calendar").is_err());
+    /// ```
+    pub fn parse<S>(uri: S) -> Result<Self, URLError>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let uri = uri.into();
+        if has_scheme(&uri) {
+            Ok(URL::new(uri))
+        } else {
+            Err(URLError)
+        }
+    }
+}
+
+// Checks for the scheme of RFC3986: ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )
+// followed by a colon
+fn has_scheme(uri: &str) -> bool {
+    match uri.find(':') {
+        Some(end) => {
+            let scheme = &uri[..end];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+        None => false,
+    }
+}
+
+impl RecurrenceID<'_> {
+    /// Sets the `RANGE` parameter and returns the property, so that the
+    /// changes apply to this and all following instances of the recurrence
+    /// set. Without it only this instance is changed.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    /// use ics::parameters::Range;
+    /// use ics::properties::RecurrenceID;
+    ///
+    /// let recurrence_id = RecurrenceID::new("19960120T120000Z").range(Range::ThisAndFuture);
+    /// assert_eq!(
+    ///     Property::from(recurrence_id).to_string(),
+    ///     "RECURRENCE-ID;RANGE=THISANDFUTURE:19960120T120000Z\r\n"
+    /// );
+    /// ```
+    pub fn range(mut self, range: Range) -> Self {
+        self.add(range);
+        self
+    }
+}
+
+impl RRule<'_> {
+    pub(crate) fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl<'a> StructuredData<'a> {
+    /// Creates a new `STRUCTURED-DATA` Property which contains a JSON document
+    /// inline. The document is escaped as text and the `FMTTYPE` parameter is
+    /// set to `application/json`. A `SCHEMA` parameter should be added as
+    /// well.
+    pub fn json(json: &str) -> Self {
+        StructuredData {
+            value: Cow::Owned(escape_text(json).into_owned()),
+            parameters: parameters!("FMTTYPE" => "application/json"),
+        }
+    }
+
+    /// Creates a new `STRUCTURED-DATA` Property which contains the data with
+    /// the media type like `application/ld+json`. The data is encoded as
+    /// base64 and the parameters `ENCODING=BASE64` and `VALUE=BINARY` are
+    /// added.
+    pub fn binary<S>(data: &[u8], fmttype: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        StructuredData {
+            value: Cow::Owned(encode_base64(data)),
+            parameters: parameters!("ENCODING" => "BASE64"; "FMTTYPE" => fmttype; "VALUE" => "BINARY"),
+        }
+    }
+}
+
+impl ExDate<'_> {
+    /// Creates a new `EXDATE` Property from a list of dates or date-times which
+    /// must all have the same type. The `VALUE=DATE` parameter is added for
+    /// dates.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::parameters::Value;
+    /// use ics::properties::ExDate;
+    ///
+    /// let mut expected = ExDate::new("20240101,20240102");
+    /// expected.add(Value::DATE);
+    /// assert_eq!(ExDate::from_dates(&["20240101", "20240102"]), expected);
+    /// ```
+    pub fn from_dates(dates: &[&str]) -> Self {
+        debug_assert!(
+            !dates.iter().any(|date| date.contains('/')),
+            "EXDATE cannot contain periods"
+        );
+        let mut property = Self::new(dates.join(","));
+        if let Some(value) = date_list_value(dates) {
+            property.add(value);
+        }
+        property
+    }
+}
+
+impl RDate<'_> {
+    /// Creates a new `RDATE` Property from a list of dates, date-times or
+    /// periods which must all have the same type. The `VALUE=DATE` or
+    /// `VALUE=PERIOD` parameter is added for dates or periods.
+    pub fn from_dates(dates: &[&str]) -> Self {
+        let mut property = Self::new(dates.join(","));
+        if let Some(value) = date_list_value(dates) {
+            property.add(value);
+        }
+        property
+    }
+}
+
+// DATE-TIME is the default value type of EXDATE and RDATE.
+fn date_list_value(dates: &[&str]) -> Option<Value<'static>> {
+    let value_type = dates
+        .first()
+        .map_or("DATE-TIME", |date| date_value_type(date));
+    debug_assert!(
+        dates.iter().all(|date| date_value_type(date) == value_type),
+        "all values must have the same type"
+    );
+    match value_type {
+        "DATE" => Some(Value::DATE),
+        "PERIOD" => Some(Value::PERIOD),
+        _ => None,
+    }
+}
+
+impl PercentComplete<'_> {
+    /// Creates a new `PERCENT-COMPLETE` property from a percentage. Values
+    /// above 100 are clamped to 100.
+    pub fn from_percent(percent: u8) -> Self {
+        Self::new(percent.min(100).to_string())
+    }
+}
+
+impl Priority<'_> {
+    /// Creates a new `PRIORITY` property from a level between 1 (highest) and
+    /// 9 (lowest). A level of 0 means that the priority is undefined.
+    pub fn from_level(level: u8) -> Self {
+        debug_assert!(level <= 9, "priority level must be between 0 and 9");
+        Self::new(level.to_string())
+    }
+}
+
+impl Sequence<'_> {
+    /// Creates a new `SEQUENCE` property from a revision number.
+    pub fn from_number(number: u32) -> Self {
+        Self::new(number.to_string())
+    }
+}
+
+impl Duration<'_> {
+    /// Creates a new `DURATION` Property from a typed duration value.
+    pub fn from_duration(duration: values::Duration) -> Self {
+        Self::new(duration.to_string())
+    }
+}
+
+impl Trigger<'_> {
+    /// Creates a new `TRIGGER` Property from a typed duration value which is
+    /// relative to the start or end of the component.
+    pub fn from_duration(duration: values::Duration) -> Self {
+        Self::new(duration.to_string())
+    }
+
+    /// Creates a new `TRIGGER` Property from a typed duration value relative
+    /// to the start or end of the component. The `RELATED` parameter is
+    /// always added, so the reference point is explicit.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    /// use ics::parameters::Related;
+    /// use ics::properties::Trigger;
+    /// use ics::values::Duration;
+    ///
+    /// let trigger = Trigger::relative(Duration::new().minutes(15).negative(), Related::Start);
+    /// assert_eq!(
+    ///     Property::from(trigger).to_string(),
+    ///     "TRIGGER;RELATED=START:-PT15M\r\n"
+    /// );
+    /// ```
+    pub fn relative(duration: values::Duration, related: Related) -> Self {
+        let mut trigger = Self::from_duration(duration);
+        trigger.add(related);
+        trigger
+    }
+
+    /// Creates a new `TRIGGER` Property from an absolute UTC date-time. It
+    /// contains `VALUE=DATE-TIME`, so it is not mistaken for a duration.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::components::Property;
+    /// use ics::properties::Trigger;
+    /// use ics::values::{Date, DateTime};
+    ///
+    /// let trigger = Trigger::absolute(DateTime::new(Date::new(1998, 1, 1), 5, 0, 0));
+    /// assert_eq!(
+    ///     Property::from(trigger).to_string(),
+    ///     "TRIGGER;VALUE=DATE-TIME:19980101T050000Z\r\n"
+    /// );
+    /// ```
+    pub fn absolute<D>(datetime: D) -> Self
+    where
+        D: Into<values::DateTime>,
+    {
+        let mut trigger = Self::new(datetime.into().to_string());
+        trigger.add(Value::DATE_TIME);
+        trigger
+    }
+}
+
+impl Created<'_> {
+    /// Creates a new `CREATED` Property with the current time in UTC. The
+    /// time is taken from the system clock. RFC5545 requires `CREATED` to be
+    /// a date-time in UTC, a local or floating time is not allowed.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::from_datetime(values::DateTime::now())
+    }
+}
+
+impl LastModified<'_> {
+    /// Creates a new `LAST-MODIFIED` Property with the current time in UTC.
+    /// The time is taken from the system clock. RFC5545 requires
+    /// `LAST-MODIFIED` to be a date-time in UTC, a local or floating time is
+    /// not allowed.
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        Self::from_datetime(values::DateTime::now())
+    }
+}
+
+impl PollMode<'_> {
+    /// Specifies that the voters choose from the candidates and the
+    /// organizer confirms one of them (default value).
+    pub fn basic() -> Self {
+        Self::new("BASIC")
+    }
+}
+
+impl Version<'_> {
+    /// Creates a new `VERSION` Property with the value `2.0` which is the
+    /// version of RFC5545 and the only one in use.
+    pub fn v2_0() -> Self {
+        Self::new("2.0")
+    }
+}
+
+impl<'a> From<Version<'a>> for Cow<'a, str> {
+    /// Returns the value of the `VERSION` Property, so that it can be passed
+    /// to [`ICalendar::new()`](crate::ICalendar::new) instead of a string.
+    fn from(version: Version<'a>) -> Self {
+        version.value
+    }
+}
+
 impl Action<'_> {
     /// Specifies an audio action to be invoked when an alarm is triggered.
     pub fn audio() -> Self {
@@ -164,6 +810,12 @@ impl Default for Class<'_> {
     }
 }
 
+impl Default for BusyType<'_> {
+    fn default() -> Self {
+        Self::busy_unavailable()
+    }
+}
+
 impl Default for Transp<'_> {
     fn default() -> Self {
         Self::opaque()
@@ -188,6 +840,12 @@ impl Default for Priority<'_> {
     }
 }
 
+impl Default for Version<'_> {
+    fn default() -> Self {
+        Self::v2_0()
+    }
+}
+
 impl Default for Repeat<'_> {
     fn default() -> Self {
         Self {
@@ -206,20 +864,453 @@ impl Default for Sequence<'_> {
     }
 }
 
+/// Builder for the value of the `RRULE` Property
+///
+/// # Example
+/// ```
+/// use ics::properties::{Frequency, RRule, RRuleBuilder, Weekday};
+///
+/// let rrule = RRuleBuilder::new()
+///     .freq(Frequency::Weekly)
+///     .by_day(&[Weekday::Monday, Weekday::Wednesday, Weekday::Friday])
+///     .count(10)
+///     .build()
+///     .unwrap();
+/// assert_eq!(rrule, RRule::new("FREQ=WEEKLY;COUNT=10;BYDAY=MO,WE,FR"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct RRuleBuilder<'a> {
+    freq: Option<Frequency>,
+    interval: Option<u32>,
+    count: Option<u32>,
+    until: Option<Cow<'a, str>>,
+    by_day: Vec<Weekday>,
+    by_month_day: Vec<i8>,
+}
+
+impl<'a> RRuleBuilder<'a> {
+    /// Creates a new empty builder. The frequency is required.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `FREQ` rule part.
+    pub fn freq(mut self, freq: Frequency) -> Self {
+        self.freq = Some(freq);
+        self
+    }
+
+    /// Sets the `INTERVAL` rule part.
+    pub fn interval(mut self, interval: u32) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Sets the `COUNT` rule part. It cannot be combined with `UNTIL`.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the `UNTIL` rule part to a date or date-time value. It cannot be
+    /// combined with `COUNT`.
+    pub fn until<S>(mut self, until: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.until = Some(until.into());
+        self
+    }
+
+    /// Sets the `BYDAY` rule part.
+    pub fn by_day(mut self, days: &[Weekday]) -> Self {
+        self.by_day = days.to_vec();
+        self
+    }
+
+    /// Sets the `BYMONTHDAY` rule part. Negative values count from the end of
+    /// the month.
+    pub fn by_month_day(mut self, days: &[i8]) -> Self {
+        self.by_month_day = days.to_vec();
+        self
+    }
+
+    /// Creates the `RRULE` Property. The frequency must be set and `COUNT`
+    /// and `UNTIL` must not be used together.
+    pub fn build(self) -> Result<RRule<'a>, RRuleError> {
+        let freq = self.freq.ok_or(RRuleError::MissingFrequency)?;
+        let mut value = format!("FREQ={}", freq.as_str());
+        match (self.count, self.until) {
+            (Some(_), Some(_)) => return Err(RRuleError::CountAndUntil),
+            (Some(count), None) => value.push_str(&format!(";COUNT={}", count)),
+            (None, Some(until)) => value.push_str(&format!(";UNTIL={}", until)),
+            (None, None) => {}
+        }
+        if let Some(interval) = self.interval {
+            value.push_str(&format!(";INTERVAL={}", interval));
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<_> = self.by_day.iter().map(Weekday::as_str).collect();
+            value.push_str(";BYDAY=");
+            value.push_str(&days.join(","));
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<_> = self.by_month_day.iter().map(i8::to_string).collect();
+            value.push_str(";BYMONTHDAY=");
+            value.push_str(&days.join(","));
+        }
+        Ok(RRule::new(value))
+    }
+}
+
+/// The error type for building an invalid `RRULE` Property
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RRuleError {
+    /// The `FREQ` rule part is required.
+    MissingFrequency,
+    /// The `COUNT` and `UNTIL` rule parts must not occur together.
+    CountAndUntil,
+}
+
+impl fmt::Display for RRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RRuleError::MissingFrequency => write!(f, "FREQ rule part is required"),
+            RRuleError::CountAndUntil => write!(f, "COUNT and UNTIL must not be used together"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RRuleError {}
+
+/// The error type for a `URL` Property whose value does not start with a URI
+/// scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct URLError;
+
+impl fmt::Display for URLError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "URL must start with a URI scheme")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for URLError {}
+
+/// `FREQ` rule part of a recurrence rule
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Frequency {
+    /// `SECONDLY`
+    Secondly,
+    /// `MINUTELY`
+    Minutely,
+    /// `HOURLY`
+    Hourly,
+    /// `DAILY`
+    Daily,
+    /// `WEEKLY`
+    Weekly,
+    /// `MONTHLY`
+    Monthly,
+    /// `YEARLY`
+    Yearly,
+}
+
+impl Frequency {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::Secondly => "SECONDLY",
+            Frequency::Minutely => "MINUTELY",
+            Frequency::Hourly => "HOURLY",
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// Day of the week in a recurrence rule
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    /// `MO`
+    Monday,
+    /// `TU`
+    Tuesday,
+    /// `WE`
+    Wednesday,
+    /// `TH`
+    Thursday,
+    /// `FR`
+    Friday,
+    /// `SA`
+    Saturday,
+    /// `SU`
+    Sunday,
+}
+
+impl Weekday {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "MO",
+            Weekday::Tuesday => "TU",
+            Weekday::Wednesday => "WE",
+            Weekday::Thursday => "TH",
+            Weekday::Friday => "FR",
+            Weekday::Saturday => "SA",
+            Weekday::Sunday => "SU",
+        }
+    }
+}
+
+/// Builder for the `ATTENDEE` property
+///
+/// The builder adds the parameters that are commonly used for attendees.
+/// Other parameters can still be added to the built property.
+///
+/// # Example
+/// ```
+/// use ics::parameters::{PartStat, Role};
+/// use ics::properties::AttendeeBuilder;
+///
+/// let attendee = AttendeeBuilder::new("mailto:jsmith@example.com")
+///     .common_name("John Smith")
+///     .role(Role::REQ_PARTICIPANT)
+///     .part_stat(PartStat::TENTATIVE)
+///     .rsvp(true)
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AttendeeBuilder<'a> {
+    attendee: Attendee<'a>,
+    delegated_to: Vec<Cow<'a, str>>,
+}
+
+impl<'a> AttendeeBuilder<'a> {
+    /// Creates a new builder for an attendee with the calendar user address
+    /// like `mailto:jsmith@example.com`.
+    pub fn new<S>(cal_address: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        AttendeeBuilder {
+            attendee: Attendee::new(cal_address),
+            delegated_to: Vec::new(),
+        }
+    }
+
+    /// Sets the `CN` parameter to the name of the attendee.
+    pub fn common_name<S>(mut self, name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.attendee.add(CN::new(name));
+        self
+    }
+
+    /// Sets the `ROLE` parameter.
+    pub fn role(mut self, role: Role<'a>) -> Self {
+        self.attendee.add(role);
+        self
+    }
+
+    /// Sets the `PARTSTAT` parameter.
+    pub fn part_stat(mut self, part_stat: PartStat<'a>) -> Self {
+        self.attendee.add(part_stat);
+        self
+    }
+
+    /// Sets the `RSVP` parameter.
+    pub fn rsvp(mut self, rsvp: bool) -> Self {
+        self.attendee
+            .add(if rsvp { RSVP::True } else { RSVP::False });
+        self
+    }
+
+    /// Adds a calendar user address to the `DELEGATED-TO` parameter. The
+    /// address is quoted as the specification requires.
+    pub fn delegated_to<S>(mut self, cal_address: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.delegated_to.push(cal_address.into());
+        self
+    }
+
+    /// Creates the `ATTENDEE` property.
+    pub fn build(mut self) -> Attendee<'a> {
+        if !self.delegated_to.is_empty() {
+            let delegated_to: DelegatedTo = self.delegated_to.into_iter().collect();
+            self.attendee.add(delegated_to);
+        }
+        self.attendee
+    }
+}
+
+/// Builder for the `ORGANIZER` property
+///
+/// The builder adds the parameters that are commonly used for organizers, e.g.
+/// `SENT-BY` if someone else sends the message on behalf of the organizer.
+///
+/// # Example
+/// ```
+/// use ics::properties::OrganizerBuilder;
+///
+/// let organizer = OrganizerBuilder::new("mailto:john@example.com")
+///     .common_name("John")
+///     .sent_by("mailto:jane@example.com")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OrganizerBuilder<'a> {
+    organizer: Organizer<'a>,
+}
+
+impl<'a> OrganizerBuilder<'a> {
+    /// Creates a new builder for an organizer with the calendar user address
+    /// like `mailto:john@example.com`.
+    pub fn new<S>(cal_address: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        OrganizerBuilder {
+            organizer: Organizer::new(cal_address),
+        }
+    }
+
+    /// Sets the `CN` parameter to the name of the organizer.
+    pub fn common_name<S>(mut self, name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.organizer.add(CN::new(name));
+        self
+    }
+
+    /// Sets the `SENT-BY` parameter to the calendar user address of the
+    /// sender.
+    pub fn sent_by<S>(mut self, cal_address: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.organizer.add(SentBy::new(cal_address));
+        self
+    }
+
+    /// Sets the `DIR` parameter to the URI of a directory entry of the
+    /// organizer.
+    pub fn dir<S>(mut self, uri: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.organizer.add(Dir::new(uri));
+        self
+    }
+
+    /// Creates the `ORGANIZER` property.
+    pub fn build(self) -> Organizer<'a> {
+        self.organizer
+    }
+}
+
+impl_from_datetime!(Completed, "COMPLETED");
+impl_from_datetime!(Created, "CREATED");
+impl_from_datetime!(DtEnd, "DTEND");
+impl_from_datetime!(DtStamp, "DTSTAMP");
+impl_from_datetime!(DtStart, "DTSTART");
+impl_from_datetime!(Due, "DUE");
+impl_from_datetime!(LastModified, "LAST-MODIFIED");
+impl_from_datetime!(RecurrenceID, "RECURRENCE-ID");
+
+impl_from_date!(DtEnd, "DTEND");
+impl_from_date!(DtStart, "DTSTART");
+impl_from_date!(Due, "DUE");
+impl_from_date!(RecurrenceID, "RECURRENCE-ID");
+
+impl_text!(Comment, "COMMENT");
+impl_text!(Contact, "CONTACT");
+impl_text!(Description, "DESCRIPTION");
+impl_text!(Summary, "SUMMARY");
+
 #[cfg(feature = "rfc7986")]
 pub use self::rfc7986::*;
 
 #[cfg(feature = "rfc7986")]
 mod rfc7986 {
     use crate::components::{Parameter, Parameters, Property};
-    use std::borrow::Cow;
-    use std::collections::BTreeMap;
+    use crate::parameters::{Feature, Label};
+    use crate::util::encode_base64;
+    use crate::values;
+    use alloc::borrow::Cow;
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
     property!(Name, "NAME");
     property_with_parameter!(RefreshInterval, "REFRESH-INTERVAL", "DURATION");
     property_with_parameter!(Source, "SOURCE", "URI");
     property!(Color, "COLOR");
     property_with_parameter!(Conference, "CONFERENCE", "URI");
 
+    impl<'a> Conference<'a> {
+        /// Creates a new `CONFERENCE` Property with the URI of the conference
+        /// like `https://chat.example.com/audio?id=123456`. This is the same as
+        /// [`Conference::new()`].
+        pub fn uri<S>(uri: S) -> Self
+        where
+            S: Into<Cow<'a, str>>,
+        {
+            Self::new(uri)
+        }
+
+        /// Sets the `FEATURE` parameter. Several features are written as
+        /// comma-separated list.
+        pub fn feature(mut self, features: &[Feature]) -> Self {
+            let features: Vec<_> = features
+                .iter()
+                .map(|feature| Parameter::from(feature.clone()).value)
+                .collect();
+            self.add(Feature::new(features.join(",")));
+            self
+        }
+
+        /// Sets the `LABEL` parameter which describes the conference to the
+        /// user.
+        pub fn label<S>(mut self, label: S) -> Self
+        where
+            S: Into<Cow<'a, str>>,
+        {
+            self.add(Label::new(label));
+            self
+        }
+    }
+
+    impl RefreshInterval<'_> {
+        /// Creates a new `REFRESH-INTERVAL` Property from a positive typed
+        /// duration value which suggests how often a subscribed calendar
+        /// should be polled for updates.
+        ///
+        /// # Example
+        /// ```
+        /// use ics::properties::RefreshInterval;
+        /// use ics::values::Duration;
+        ///
+        /// let interval = RefreshInterval::from_duration(Duration::new().hours(12));
+        /// assert_eq!(interval, RefreshInterval::new("PT12H"));
+        /// ```
+        pub fn from_duration(duration: values::Duration) -> Self {
+            Self::new(duration.to_string())
+        }
+    }
+
+    impl Color<'_> {
+        /// Creates a new `COLOR` Property from a CSS3 color name like
+        /// `turquoise`. The value of `COLOR` should be a lowercase CSS3 color
+        /// keyword which is why the name is converted to lowercase.
+        pub fn from_css_name(name: &str) -> Self {
+            Self::new(name.to_ascii_lowercase())
+        }
+    }
+
     /// `IMAGE` Property
     ///
     /// Newer properties that have a different value type than `TEXT` have to
@@ -245,16 +1336,17 @@ mod rfc7986 {
             }
         }
 
-        /// Creates a new `IMAGE` Property with the given value.
-        /// The value type is `BINARY` which is why the `ENCODING` parameter
-        /// with the value `BASE64` is also added.
-        pub fn binary<S>(value: S) -> Self
+        /// Creates a new `IMAGE` Property from the data of an image with the
+        /// media type like `image/png`. The value type is `BINARY` which is
+        /// why the data is encoded as base64 and the `ENCODING` parameter with
+        /// the value `BASE64` is also added.
+        pub fn binary<S>(data: &[u8], fmttype: S) -> Self
         where
             S: Into<Cow<'a, str>>,
         {
             Image {
-                value: value.into(),
-                parameters: parameters!("ENCODING" => "BASE64"; "VALUE" => "BINARY"),
+                value: Cow::Owned(encode_base64(data)),
+                parameters: parameters!("ENCODING" => "BASE64"; "FMTTYPE" => fmttype; "VALUE" => "BINARY"),
             }
         }
     }
@@ -262,4 +1354,37 @@ mod rfc7986 {
     impl_add_parameters!(Image);
 
     impl_from_prop!(Image, "IMAGE");
+
+    /// `EMAIL` Property
+    ///
+    /// The email address of a contact for the calendar or of the recipient of
+    /// an `EMAIL` alarm.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct Email<'a> {
+        value: Cow<'a, str>,
+        parameters: Parameters<'a>,
+    }
+
+    impl<'a> Email<'a> {
+        /// Creates a new `EMAIL` Property with the given email address like
+        /// `jane@example.com`.
+        ///
+        /// # Panics
+        /// In debug builds this panics if the address does not contain an `@`.
+        pub fn new<S>(address: S) -> Self
+        where
+            S: Into<Cow<'a, str>>,
+        {
+            let value = address.into();
+            debug_assert!(value.contains('@'), "email address must contain an `@`");
+            Email {
+                value,
+                parameters: BTreeMap::new(),
+            }
+        }
+    }
+
+    impl_add_parameters!(Email);
+
+    impl_from_prop!(Email, "EMAIL");
 }
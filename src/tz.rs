@@ -0,0 +1,191 @@
+//! Time zone definitions from the IANA time zone database.
+use crate::components::Property;
+use crate::ical::{Daylight, Standard, TimeZone};
+use crate::properties::{RRule, TzName};
+use crate::values::{Date, UtcOffset};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Offset, TimeZone as _, Timelike};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+
+impl TimeZone<'static> {
+    /// Creates the `VTIMEZONE` calendar component for a time zone of the IANA
+    /// time zone database with the rules that are in effect in the given year.
+    ///
+    /// Every change of the offset in that year becomes a `STANDARD` or
+    /// `DAYLIGHT` definition. If the change happens on the same weekday of the
+    /// month in the following year, the definition recurs yearly. A time zone
+    /// without changes has a single `STANDARD` definition. Local times before
+    /// the given year are not covered by the definitions.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::TimeZone;
+    ///
+    /// let timezone = TimeZone::from_tz(chrono_tz::Europe::Berlin, 2024);
+    /// assert!(timezone.to_string().contains("RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU"));
+    /// ```
+    pub fn from_tz(tz: Tz, year: i32) -> Self {
+        let following = transitions(tz, year + 1);
+        let mut timezone: Option<TimeZone<'static>> = None;
+        for transition in transitions(tz, year) {
+            let recurs = following.iter().any(|next| next.same_rule(&transition));
+            let definition = transition.properties(recurs);
+            let (dtstart, from, to) = (
+                transition.dtstart(),
+                offset(transition.from),
+                offset(transition.to),
+            );
+            if transition.daylight {
+                let mut daylight = Daylight::new(dtstart, from, to);
+                definition.into_iter().for_each(|p| daylight.push(p));
+                match timezone.as_mut() {
+                    Some(timezone) => timezone.add_daylight(daylight),
+                    None => timezone = Some(TimeZone::daylight(tz.name(), daylight)),
+                }
+            } else {
+                let mut standard = Standard::new(dtstart, from, to);
+                definition.into_iter().for_each(|p| standard.push(p));
+                match timezone.as_mut() {
+                    Some(timezone) => timezone.add_standard(standard),
+                    None => timezone = Some(TimeZone::standard(tz.name(), standard)),
+                }
+            }
+        }
+
+        timezone.unwrap_or_else(|| {
+            let start = start_of_year(year).unwrap_or_default();
+            let current = tz.offset_from_utc_datetime(&start);
+            let utc_offset = offset(current.fix().local_minus_utc());
+            let mut standard = Standard::new("19700101T000000", utc_offset.clone(), utc_offset);
+            if let Some(name) = current.abbreviation() {
+                standard.push(TzName::new(name.to_owned()));
+            }
+            TimeZone::standard(tz.name(), standard)
+        })
+    }
+}
+
+// A change of the UTC offset with the offsets in seconds
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Transition {
+    // The local time of the change in the previous offset
+    onset: NaiveDateTime,
+    from: i32,
+    to: i32,
+    daylight: bool,
+    name: Option<String>,
+}
+
+impl Transition {
+    fn dtstart(&self) -> String {
+        format!(
+            "{}T{:02}{:02}{:02}",
+            Date::from(self.onset.date()),
+            self.onset.hour(),
+            self.onset.minute(),
+            self.onset.second()
+        )
+    }
+
+    // The rule is the nth weekday of the month, or the last one if the
+    // weekday occurs in the final week of the month.
+    fn rule(&self) -> String {
+        let date = self.onset.date();
+        let week = if date.day() + 7 > days_in_month(date) {
+            -1
+        } else {
+            (date.day() as i32 - 1) / 7 + 1
+        };
+        format!(
+            "FREQ=YEARLY;BYMONTH={};BYDAY={}{}",
+            date.month(),
+            week,
+            weekday(date.weekday())
+        )
+    }
+
+    fn same_rule(&self, other: &Transition) -> bool {
+        self.from == other.from && self.to == other.to && self.rule() == other.rule()
+    }
+
+    fn properties(&self, recurs: bool) -> Vec<Property<'static>> {
+        let mut properties = Vec::new();
+        if recurs {
+            properties.push(RRule::new(self.rule()).into());
+        }
+        if let Some(name) = &self.name {
+            properties.push(TzName::new(name.clone()).into());
+        }
+        properties
+    }
+}
+
+// Finds the changes of the offset by checking every hour of the year and
+// then every minute of the hour in which the offset changed.
+fn transitions(tz: Tz, year: i32) -> Vec<Transition> {
+    let (start, end) = match (start_of_year(year), start_of_year(year + 1)) {
+        (Some(start), Some(end)) => (start, end),
+        _ => return Vec::new(),
+    };
+    let offset_at = |time: &NaiveDateTime| {
+        let offset = tz.offset_from_utc_datetime(time);
+        (offset.fix().local_minus_utc(), offset.dst_offset(), offset)
+    };
+
+    let mut transitions = Vec::new();
+    let mut time = start;
+    let mut current = offset_at(&time);
+    while time < end {
+        let next_hour = time + Duration::hours(1);
+        let next = offset_at(&next_hour);
+        if (next.0, next.1) != (current.0, current.1) {
+            let change = (1..=60)
+                .map(|minute| time + Duration::minutes(minute))
+                .find(|time| {
+                    let offset = offset_at(time);
+                    (offset.0, offset.1) != (current.0, current.1)
+                })
+                .unwrap_or(next_hour);
+            let changed = offset_at(&change);
+            transitions.push(Transition {
+                onset: change + Duration::seconds(i64::from(current.0)),
+                from: current.0,
+                to: changed.0,
+                daylight: !changed.1.is_zero(),
+                name: changed.2.abbreviation().map(str::to_owned),
+            });
+        }
+        time = next_hour;
+        current = next;
+    }
+    transitions
+}
+
+fn start_of_year(year: i32) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(year, 1, 1).and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map_or(31, |last| last.day())
+}
+
+fn weekday(weekday: chrono::Weekday) -> &'static str {
+    match weekday {
+        chrono::Weekday::Mon => "MO",
+        chrono::Weekday::Tue => "TU",
+        chrono::Weekday::Wed => "WE",
+        chrono::Weekday::Thu => "TH",
+        chrono::Weekday::Fri => "FR",
+        chrono::Weekday::Sat => "SA",
+        chrono::Weekday::Sun => "SU",
+    }
+}
+
+fn offset(seconds: i32) -> String {
+    UtcOffset::from_minutes((seconds / 60) as i16).to_string()
+}
@@ -1,7 +1,9 @@
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::String;
 
 /// Escapes comma, semicolon, backslash and newline character by prepending a
-/// backslash. Newlines are normalized to a line feed character.
+/// backslash. Newlines (`\r\n`, `\r` and `\n`) are normalized to a line feed
+/// character, so the output never contains a carriage return.
 ///
 /// This method is only necessary for properties with the value type "TEXT".
 ///
@@ -59,9 +61,107 @@ where
     }
 }
 
+/// Converts every line ending (`\r\n`, `\r` and `\n`) to a line feed
+/// character without escaping anything else.
+///
+/// This is meant for text with mixed line endings, e.g. from files, that is
+/// processed further before it is escaped, like comparing or hashing it.
+/// [`escape_text`] applies the same normalization itself, so it is not
+/// necessary to call this first for a consistent escaped `\n`.
+///
+/// # Example
+/// ```
+/// use ics::{escape_text, normalize_text};
+///
+/// let normalized = normalize_text("Line one\r\nLine two\rLine three\n");
+/// assert_eq!(normalized, "Line one\nLine two\nLine three\n");
+/// assert_eq!(escape_text(normalized), "Line one\\nLine two\\nLine three\\n");
+/// ```
+pub fn normalize_text<'a, S>(input: S) -> Cow<'a, str>
+where
+    S: Into<Cow<'a, str>>,
+{
+    let input = input.into();
+    if !input.contains('\r') {
+        return input;
+    }
+    Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// Reverses [`escape_text`] by removing the backslash of escaped commas,
+/// semicolons and backslashes. The literal `\n` or `\N` is converted to a
+/// line feed character. Unrecognized escape sequences are left intact.
+///
+/// # Example
+/// ```
+/// use ics::unescape_text;
+///
+/// let line = "Hello\\, World!\\nCharacters like \\; or \\\\ were escaped.";
+/// let expected = "Hello, World!\nCharacters like ; or \\ were escaped.";
+/// assert_eq!(expected, unescape_text(line));
+/// ```
+pub fn unescape_text<'a, S>(input: S) -> Cow<'a, str>
+where
+    S: Into<Cow<'a, str>>,
+{
+    let input = input.into();
+    if !input.contains('\\') {
+        return input;
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => output.push('\n'),
+            Some(c @ ',') | Some(c @ ';') | Some(c @ '\\') => output.push(c),
+            Some(c) => {
+                output.push('\\');
+                output.push(c);
+            }
+            None => output.push('\\'),
+        }
+    }
+    Cow::Owned(output)
+}
+
+// Encodes binary data as base64 with padding for inline values (see
+// [RFC4648 4. Base 64 Encoding](https://tools.ietf.org/html/rfc4648#section-4)).
+pub(crate) fn encode_base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut output = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+        let indices = [
+            b[0] >> 2,
+            (b[0] & 0b11) << 4 | b[1] >> 4,
+            (b[1] & 0b1111) << 2 | b[2] >> 6,
+            b[2] & 0b11_1111,
+        ];
+        for (i, &index) in indices.iter().enumerate() {
+            if i <= chunk.len() {
+                output.push(ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
 #[cfg(test)]
 mod escape_text_tests {
-    use super::escape_text;
+    use super::{escape_text, normalize_text, unescape_text};
+    use alloc::borrow::Cow;
 
     #[test]
     fn escaped_chars() {
@@ -70,10 +170,28 @@ mod escape_text_tests {
         assert_eq!(expected, escape_text(s));
     }
 
+    #[test]
+    fn carriage_returns() {
+        assert_eq!("a\\nb", escape_text("a\r\nb"));
+        assert_eq!("a\\nb", escape_text("a\rb"));
+        assert_eq!("a\\nb", escape_text("a\nb"));
+    }
+
+    #[test]
+    fn mixed_line_endings() {
+        let input = "a\r\nb\rc\nd\r\r\n";
+        assert_eq!(normalize_text(input), "a\nb\nc\nd\n\n");
+        assert_eq!(escape_text(normalize_text(input)), escape_text(input));
+        match normalize_text("a\nb") {
+            Cow::Borrowed(normalized) => assert_eq!(normalized, "a\nb"),
+            Cow::Owned(_) => panic!("text without carriage returns was allocated"),
+        }
+    }
+
     #[test]
     fn no_escaped_chars() {
         let s = "This is a simple sentence.";
-        let expected = s.clone();
+        let expected = s;
         assert_eq!(expected, escape_text(s));
     }
 
@@ -89,4 +207,55 @@ mod escape_text_tests {
         );
         assert_eq!(expected_value, property.value);
     }
+
+    #[test]
+    fn unescaped_chars() {
+        let s = "\\,\\n\\N\\;:\\\\ ö";
+        let expected = ",\n\n;:\\ ö";
+        assert_eq!(expected, unescape_text(s));
+    }
+
+    #[test]
+    fn unknown_escape_sequences() {
+        let s = "C:\\Users\\x and a trailing \\";
+        assert_eq!(s, unescape_text(s));
+    }
+
+    #[test]
+    fn no_unescaped_chars() {
+        let s = "This is a simple sentence.";
+        match unescape_text(s) {
+            Cow::Borrowed(unescaped) => assert_eq!(s, unescaped),
+            Cow::Owned(_) => panic!("text without escaped characters was allocated"),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let s = "Networld+Interop Conference and Exhibit\n\
+                 Atlanta World Congress Center\n\
+                 Atlanta, Georgia";
+        assert_eq!(s, unescape_text(escape_text(s)));
+    }
+}
+
+#[cfg(test)]
+mod base64_tests {
+    use super::encode_base64;
+
+    #[test]
+    fn rfc4648_vectors() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foob"), "Zm9vYg==");
+        assert_eq!(encode_base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn all_bits() {
+        assert_eq!(encode_base64(&[0xff, 0xfe, 0x00]), "//4A");
+    }
 }
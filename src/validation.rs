@@ -0,0 +1,316 @@
+//! Checks for the rules of the specification that the types cannot enforce.
+use crate::components::{self, Property};
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// The error type for iCalendar objects that violate the specification
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ValidationError {
+    /// The component is missing a required property.
+    MissingProperty {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the missing property
+        property: &'static str,
+    },
+    /// The component is missing a required sub-component.
+    MissingComponent {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the missing sub-component
+        subcomponent: &'static str,
+    },
+    /// The value of a property is not valid.
+    InvalidValue {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the property
+        property: &'static str,
+    },
+    /// The values of two properties contradict each other.
+    Inconsistent {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the first property
+        property: &'static str,
+        /// Name of the second property
+        other: &'static str,
+    },
+    /// The component contains two properties that must not occur together.
+    Exclusive {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the first property
+        property: &'static str,
+        /// Name of the second property
+        other: &'static str,
+    },
+    /// The value of a property contains a control character that is not
+    /// allowed in content lines.
+    ControlCharacter {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the property
+        property: String,
+        /// Byte offset of the control character in the value
+        offset: usize,
+    },
+    /// A parameter value contains a double quote that is not one of the quotes
+    /// around the value.
+    DoubleQuote {
+        /// Name of the component
+        component: &'static str,
+        /// Name of the property
+        property: String,
+        /// Name of the parameter
+        parameter: String,
+    },
+    /// Two components of the calendar have the same `UID` without a different
+    /// `RECURRENCE-ID` distinguishing them.
+    DuplicateUID {
+        /// Value of the `UID` property
+        uid: String,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::MissingProperty {
+                component,
+                property,
+            } => write!(
+                f,
+                "{} is missing the required {} property",
+                component, property
+            ),
+            ValidationError::MissingComponent {
+                component,
+                subcomponent,
+            } => write!(
+                f,
+                "{} is missing the required {} component",
+                component, subcomponent
+            ),
+            ValidationError::InvalidValue {
+                component,
+                property,
+            } => write!(f, "{} has an invalid {} property", component, property),
+            ValidationError::Inconsistent {
+                component,
+                property,
+                other,
+            } => write!(
+                f,
+                "{} has inconsistent {} and {} properties",
+                component, property, other
+            ),
+            ValidationError::Exclusive {
+                component,
+                property,
+                other,
+            } => write!(
+                f,
+                "{} must not contain both the {} and {} properties",
+                component, property, other
+            ),
+            ValidationError::ControlCharacter {
+                component,
+                property,
+                offset,
+            } => write!(
+                f,
+                "{} has a control character in the {} property at byte {}",
+                component, property, offset
+            ),
+            ValidationError::DoubleQuote {
+                component,
+                property,
+                parameter,
+            } => write!(
+                f,
+                "{} has a double quote in the {} parameter of the {} property",
+                component, parameter, property
+            ),
+            ValidationError::DuplicateUID { uid } => {
+                write!(
+                    f,
+                    "VCALENDAR contains several components with the UID {}",
+                    uid
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+pub(crate) fn find<'a, 'b>(properties: &'b [Property<'a>], name: &str) -> Option<&'b Property<'a>> {
+    properties
+        .iter()
+        .find(|property| property.key.eq_ignore_ascii_case(name))
+}
+
+// Checks that each of the properties occurs at least once.
+pub(crate) fn require(
+    component: &'static str,
+    properties: &[Property],
+    names: &[&'static str],
+) -> Result<(), ValidationError> {
+    match names.iter().find(|name| find(properties, name).is_none()) {
+        Some(property) => Err(ValidationError::MissingProperty {
+            component,
+            property,
+        }),
+        None => Ok(()),
+    }
+}
+
+// Checks that the values contain no control characters except for horizontal
+// tabs and line breaks, which are escaped when the property is written, and
+// that parameter values contain no double quotes.
+pub(crate) fn check_values(
+    component: &'static str,
+    properties: &[Property],
+) -> Result<(), ValidationError> {
+    let is_invalid = |c: char| c != '\t' && c != '\r' && c != '\n' && (c < ' ' || c == '\x7f');
+    for property in properties {
+        if let Some(offset) = property.value.find(is_invalid) {
+            return Err(ValidationError::ControlCharacter {
+                component,
+                property: property.key.to_string(),
+                offset,
+            });
+        }
+        for (key, value) in &property.parameters {
+            if !components::is_valid_parameter_value(key, value) {
+                return Err(ValidationError::DoubleQuote {
+                    component,
+                    property: property.key.to_string(),
+                    parameter: key.to_string(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+// Returns the value type of a single DATE, DATE-TIME or PERIOD value.
+pub(crate) fn date_value_type(value: &str) -> &'static str {
+    if value.contains('/') {
+        "PERIOD"
+    } else if value.contains('T') {
+        "DATE-TIME"
+    } else {
+        "DATE"
+    }
+}
+
+// Returns the value type of a date or date-time property. The value is used if
+// the VALUE parameter is missing.
+fn date_property_type<'b>(property: &'b Property) -> &'b str {
+    property
+        .parameters
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case("VALUE"))
+        .map_or_else(
+            || date_value_type(&property.value),
+            |(_, value)| value.as_ref(),
+        )
+}
+
+// Checks that the end of the component is either given by the end property or
+// by DURATION, and that the end has the same value type as DTSTART.
+pub(crate) fn check_end(
+    component: &'static str,
+    properties: &[Property],
+    end: &'static str,
+) -> Result<(), ValidationError> {
+    let end_property = find(properties, end);
+    if end_property.is_some() && find(properties, "DURATION").is_some() {
+        return Err(ValidationError::Exclusive {
+            component,
+            property: end,
+            other: "DURATION",
+        });
+    }
+    if let (Some(start), Some(end_property)) = (find(properties, "DTSTART"), end_property) {
+        let start_type = date_property_type(start);
+        if !start_type.eq_ignore_ascii_case(date_property_type(end_property)) {
+            return Err(ValidationError::Inconsistent {
+                component,
+                property: "DTSTART",
+                other: end,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Checks that the end property does not precede DTSTART. The values are only
+// compared if they have the same form and time zone, otherwise converting them
+// would require the time zone definitions.
+pub(crate) fn check_start_before(
+    component: &'static str,
+    properties: &[Property],
+    end: &'static str,
+) -> Result<(), ValidationError> {
+    let (start, end_property) = match (find(properties, "DTSTART"), find(properties, end)) {
+        (Some(start), Some(end_property)) => (start, end_property),
+        _ => return Ok(()),
+    };
+    let tzid = |property: &Property| {
+        property
+            .parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("TZID"))
+            .map(|(_, value)| value.to_ascii_uppercase())
+    };
+    let comparable = start.value.len() == end_property.value.len()
+        && start.value.ends_with('Z') == end_property.value.ends_with('Z')
+        && tzid(start) == tzid(end_property);
+    if comparable && end_property.value < start.value {
+        return Err(ValidationError::Inconsistent {
+            component,
+            property: "DTSTART",
+            other: end,
+        });
+    }
+    Ok(())
+}
+
+// Checks that all values of the EXDATE and RDATE properties have the type of
+// the VALUE parameter which defaults to DATE-TIME. EXDATE cannot contain
+// periods.
+pub(crate) fn check_date_lists(
+    component: &'static str,
+    properties: &[Property],
+) -> Result<(), ValidationError> {
+    for property in properties {
+        let (name, types): (_, &[&str]) = if property.key.eq_ignore_ascii_case("EXDATE") {
+            ("EXDATE", &["DATE-TIME", "DATE"])
+        } else if property.key.eq_ignore_ascii_case("RDATE") {
+            ("RDATE", &["DATE-TIME", "DATE", "PERIOD"])
+        } else {
+            continue;
+        };
+        let expected = property
+            .parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("VALUE"))
+            .map_or("DATE-TIME", |(_, value)| value.as_ref());
+        let valid = types.iter().any(|t| t.eq_ignore_ascii_case(expected))
+            && property
+                .value
+                .split(',')
+                .all(|value| date_value_type(value).eq_ignore_ascii_case(expected));
+        if !valid {
+            return Err(ValidationError::InvalidValue {
+                component,
+                property: name,
+            });
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,400 @@
+//! Typed values for properties with a specific value type.
+//!
+//! Property values are text which is why every property can be created from a
+//! string. However, some value types have a format that is easy to get wrong.
+//! The types in this module render to the correct text representation and can
+//! be passed to the respective property constructors.
+//!
+//! # Example
+//! ```
+//! use ics::properties::Trigger;
+//! use ics::values::Duration;
+//!
+//! let duration = Duration::new().minutes(15).negative();
+//! assert_eq!(duration.to_string(), "-PT15M");
+//! assert_eq!(Trigger::from_duration(duration), Trigger::new("-PT15M"));
+//! ```
+use core::fmt;
+
+/// `DURATION` value type
+///
+/// Zero components are omitted. Weeks can only be represented on their own,
+/// so they are converted to days if any other component is set. (see [RFC5545 3.3.6. Duration](https://tools.ietf.org/html/rfc5545#section-3.3.6))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Duration {
+    negative: bool,
+    weeks: u32,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+}
+
+impl Duration {
+    /// Creates a new duration of zero seconds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of weeks.
+    pub fn weeks(mut self, weeks: u32) -> Self {
+        self.weeks = weeks;
+        self
+    }
+
+    /// Sets the number of days.
+    pub fn days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Sets the number of hours.
+    pub fn hours(mut self, hours: u32) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Sets the number of minutes.
+    pub fn minutes(mut self, minutes: u32) -> Self {
+        self.minutes = minutes;
+        self
+    }
+
+    /// Sets the number of seconds.
+    pub fn seconds(mut self, seconds: u32) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Marks the duration as negative, e.g. for a trigger before the start of
+    /// an event.
+    pub fn negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+
+        let time = [(self.hours, "H"), (self.minutes, "M"), (self.seconds, "S")];
+        let first = time.iter().position(|&(value, _)| value > 0);
+        let last = time.iter().rposition(|&(value, _)| value > 0);
+        if self.days == 0 && first.is_none() {
+            if self.weeks > 0 {
+                return write!(f, "{}W", self.weeks);
+            }
+            return write!(f, "T0S");
+        }
+
+        // Computed in 64 bits because the weeks as days do not fit in 32 bits.
+        let days = u64::from(self.weeks) * 7 + u64::from(self.days);
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+        if let (Some(first), Some(last)) = (first, last) {
+            write!(f, "T")?;
+            // The time components must not skip a unit in between.
+            for &(value, unit) in &time[first..=last] {
+                write!(f, "{}{}", value, unit)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `DATE` value type
+///
+/// A calendar date which is written in the basic format like `19970714`. (see [RFC5545 3.3.4. Date](https://tools.ietf.org/html/rfc5545#section-3.3.4))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Creates a new date. The month and day start at 1.
+    pub fn new(year: u16, month: u8, day: u8) -> Self {
+        debug_assert!(year <= 9999, "year must have four digits");
+        debug_assert!((1..=12).contains(&month), "month must be between 1 and 12");
+        debug_assert!((1..=31).contains(&day), "day must be between 1 and 31");
+        Date { year, month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(date: chrono::NaiveDate) -> Self {
+        use chrono::Datelike;
+        Date::new(date.year() as u16, date.month() as u8, date.day() as u8)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for Date {
+    fn from(date: time::Date) -> Self {
+        Date::new(date.year() as u16, u8::from(date.month()), date.day())
+    }
+}
+
+/// `DATE-TIME` value type in UTC
+///
+/// A date with the time of the day in UTC which is written in the basic format
+/// like `19980119T070000Z`. (see [RFC5545 3.3.5. Date-Time](https://tools.ietf.org/html/rfc5545#section-3.3.5))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DateTime {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl DateTime {
+    /// Creates a new UTC date-time from a date and the time of the day. A
+    /// second of 60 stands for a leap second.
+    pub fn new(date: Date, hour: u8, minute: u8, second: u8) -> Self {
+        debug_assert!(hour < 24, "hour must be less than 24");
+        debug_assert!(minute < 60, "minute must be less than 60");
+        debug_assert!(second <= 60, "second must be at most 60");
+        DateTime {
+            date,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Returns the current time from the system clock.
+    #[cfg(feature = "std")]
+    pub(crate) fn now() -> Self {
+        let seconds = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        Self::from_unix_seconds(seconds)
+    }
+
+    #[cfg(feature = "std")]
+    fn from_unix_seconds(seconds: u64) -> Self {
+        let (year, month, day) = crate::expand::civil_from_days((seconds / 86_400) as i64);
+        let time = seconds % 86_400;
+        DateTime::new(
+            Date::new(year as u16, month as u8, day as u8),
+            (time / 3600) as u8,
+            (time % 3600 / 60) as u8,
+            (time % 60) as u8,
+        )
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}{:02}{:02}Z",
+            self.date, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        use chrono::Timelike;
+        DateTime::new(
+            datetime.date_naive().into(),
+            datetime.hour() as u8,
+            datetime.minute() as u8,
+            datetime.second() as u8,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for DateTime {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        let datetime = datetime.to_offset(time::UtcOffset::UTC);
+        DateTime::new(
+            datetime.date().into(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+        )
+    }
+}
+
+/// `PERIOD` value type
+///
+/// A period of time in UTC which is written with the start and either the end
+/// like `19970101T180000Z/19970102T070000Z` or a duration like
+/// `19970101T180000Z/PT5H30M`. (see [RFC5545 3.3.9. Period of Time](https://tools.ietf.org/html/rfc5545#section-3.3.9))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Period {
+    start: DateTime,
+    end: PeriodEnd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum PeriodEnd {
+    End(DateTime),
+    Duration(Duration),
+}
+
+impl Period {
+    /// Creates a new period from the start and a positive duration.
+    pub fn start_duration(start: DateTime, duration: Duration) -> Self {
+        debug_assert!(!duration.negative, "duration must be positive");
+        Period {
+            start,
+            end: PeriodEnd::Duration(duration),
+        }
+    }
+
+    /// Creates a new period from the start and the end, which must be after
+    /// the start.
+    pub fn start_end(start: DateTime, end: DateTime) -> Self {
+        debug_assert!(start < end, "start must be before the end");
+        Period {
+            start,
+            end: PeriodEnd::End(end),
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.end {
+            PeriodEnd::End(end) => write!(f, "{}/{}", self.start, end),
+            PeriodEnd::Duration(duration) => write!(f, "{}/{}", self.start, duration),
+        }
+    }
+}
+
+/// `UTC-OFFSET` value type
+///
+/// The offset of a local time from UTC which is written with a sign, hours and
+/// minutes like `-0500`. (see [RFC5545 3.3.14. UTC Offset](https://tools.ietf.org/html/rfc5545#section-3.3.14))
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct UtcOffset {
+    minutes: i16,
+}
+
+impl UtcOffset {
+    /// Creates a new offset from a signed number of minutes, e.g. `-300` for
+    /// five hours behind UTC.
+    pub fn from_minutes(minutes: i16) -> Self {
+        debug_assert!(
+            minutes > -24 * 60 && minutes < 24 * 60,
+            "offset must be less than a day"
+        );
+        UtcOffset { minutes }
+    }
+}
+
+impl fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // A zero offset must be written as positive.
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.abs();
+        write!(f, "{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateTime, Duration, Period, UtcOffset};
+
+    #[test]
+    fn weeks() {
+        assert_eq!(Duration::new().weeks(1).to_string(), "P1W");
+    }
+
+    #[test]
+    fn negative_minutes() {
+        assert_eq!(Duration::new().minutes(15).negative().to_string(), "-PT15M");
+    }
+
+    #[test]
+    fn mixed() {
+        let duration = Duration::new().days(1).hours(6);
+        assert_eq!(duration.to_string(), "P1DT6H");
+        let duration = Duration::new().weeks(1).days(1).seconds(20);
+        assert_eq!(duration.to_string(), "P8DT20S");
+        let duration = Duration::new().hours(1).seconds(5);
+        assert_eq!(duration.to_string(), "PT1H0M5S");
+        let duration = Duration::new().hours(1).minutes(30).negative();
+        assert_eq!(duration.to_string(), "-PT1H30M");
+    }
+
+    #[test]
+    fn large_weeks() {
+        let duration = Duration::new().weeks(u32::MAX).days(u32::MAX);
+        assert_eq!(duration.to_string(), "P34359738360D");
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Duration::new().to_string(), "PT0S");
+    }
+
+    #[test]
+    fn date() {
+        assert_eq!(Date::new(1997, 7, 14).to_string(), "19970714");
+        assert_eq!(Date::new(800, 1, 1).to_string(), "08000101");
+    }
+
+    #[test]
+    fn date_time() {
+        let datetime = DateTime::new(Date::new(1998, 1, 19), 7, 0, 0);
+        assert_eq!(datetime.to_string(), "19980119T070000Z");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unix_seconds() {
+        assert_eq!(
+            DateTime::from_unix_seconds(0).to_string(),
+            "19700101T000000Z"
+        );
+        let datetime = DateTime::from_unix_seconds(885_193_200);
+        assert_eq!(datetime.to_string(), "19980119T070000Z");
+    }
+
+    #[test]
+    fn period() {
+        let start = DateTime::new(Date::new(1997, 3, 8), 16, 0, 0);
+        let period = Period::start_duration(start.clone(), Duration::new().hours(3));
+        assert_eq!(period.to_string(), "19970308T160000Z/PT3H");
+        let end = DateTime::new(Date::new(1997, 3, 8), 19, 0, 0);
+        let period = Period::start_end(start, end);
+        assert_eq!(period.to_string(), "19970308T160000Z/19970308T190000Z");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "start must be before the end")]
+    fn period_end_before_start() {
+        let start = DateTime::new(Date::new(1997, 3, 8), 16, 0, 0);
+        let end = DateTime::new(Date::new(1997, 3, 8), 15, 0, 0);
+        Period::start_end(start, end);
+    }
+
+    #[test]
+    fn utc_offset() {
+        assert_eq!(UtcOffset::from_minutes(60).to_string(), "+0100");
+        assert_eq!(UtcOffset::from_minutes(-300).to_string(), "-0500");
+        assert_eq!(UtcOffset::from_minutes(330).to_string(), "+0530");
+        assert_eq!(UtcOffset::from_minutes(0).to_string(), "+0000");
+    }
+}
@@ -0,0 +1,330 @@
+//! Writing iCalendar objects component by component.
+use crate::components::{Component, Property};
+use crate::ical::{Availability, Event, FreeBusy, Journal, Poll, TimeZone, ToDo, WriteOptions};
+use crate::properties::{ProdID, Version};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt;
+use std::io::{self, BufWriter, Write};
+
+/// A writer that streams an iCalendar object without keeping its components
+/// in memory.
+///
+/// `BEGIN:VCALENDAR` and the required properties are written when the writer
+/// is created. Afterwards more calendar properties and then the components can
+/// be written one at a time. [`CalendarWriter::finish()`] writes
+/// `END:VCALENDAR`, otherwise it is written when the writer is dropped and
+/// errors are ignored. The output is buffered internally.
+///
+/// For the smallest possible output the writer can omit everything that does
+/// not change the meaning of the calendar (see [`CalendarWriter::compact()`]).
+///
+/// # Example
+/// ```
+/// use ics::{CalendarWriter, Event};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs")?;
+/// for uid in 0..3 {
+///     writer.write_event(&Event::new(uid.to_string(), "19960704T120000Z"))?;
+/// }
+/// let output = writer.finish()?;
+/// assert!(output.ends_with(b"END:VEVENT\r\nEND:VCALENDAR\r\n"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CalendarWriter<W: Write> {
+    writer: Option<BufWriter<W>>,
+    has_components: bool,
+    compact: bool,
+    options: WriteOptions,
+}
+
+impl<W: Write> CalendarWriter<W> {
+    /// Creates a new writer and writes the beginning of the iCalendar object
+    /// with the `VERSION` and `PRODID` properties.
+    pub fn new<'a, V, P>(writer: W, version: V, prodid: P) -> io::Result<Self>
+    where
+        V: Into<Cow<'a, str>>,
+        P: Into<Cow<'a, str>>,
+    {
+        let mut writer = CalendarWriter {
+            writer: Some(BufWriter::new(writer)),
+            has_components: false,
+            compact: false,
+            options: WriteOptions::default(),
+        };
+        writer.write_display(&"BEGIN:VCALENDAR\r\n")?;
+        writer.write_display(&Property::from(Version::new(version)))?;
+        writer.write_display(&Property::from(ProdID::new(prodid)))?;
+        Ok(writer)
+    }
+
+    /// Makes the writer omit properties with an empty value and parameters
+    /// that have their default value like `VALUE=DATE-TIME` for `DTSTART` or
+    /// `RSVP=FALSE`.
+    ///
+    /// Folding is still required, so lines are folded after 75 bytes as
+    /// usual. The whitespace at the beginning of a continuation line belongs
+    /// to the fold and cannot be left out either. Each component is copied to
+    /// remove the parts before it is written.
+    ///
+    /// # Example
+    /// ```
+    /// use ics::parameters::Value;
+    /// use ics::properties::DtStart;
+    /// use ics::{CalendarWriter, Event};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// let mut writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs")?.compact();
+    /// let mut event = Event::new("1", "19960704T120000Z");
+    /// let mut dtstart = DtStart::new("19960918T143000Z");
+    /// dtstart.add(Value::DATE_TIME);
+    /// event.push(dtstart);
+    /// writer.write_event(&event)?;
+    /// let output = String::from_utf8(writer.finish()?).unwrap();
+    /// assert!(output.contains("\r\nDTSTART:19960918T143000Z\r\n"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Makes the writer omit the CRLF after `END:VCALENDAR` like
+    /// [`WriteOptions::trailing_line_break()`] does for
+    /// [`ICalendar::write_with_options()`](crate::ICalendar::write_with_options).
+    pub fn without_trailing_line_break(mut self) -> Self {
+        self.options = self.options.trailing_line_break(false);
+        self
+    }
+
+    /// Writes a calendar property. Calendar properties must be written before
+    /// the components, otherwise an error of the kind `InvalidInput` is
+    /// returned.
+    pub fn write_property<'a, P>(&mut self, property: P) -> io::Result<()>
+    where
+        P: Into<Property<'a>>,
+    {
+        if self.has_components {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "calendar properties must be written before the components",
+            ));
+        }
+        let mut property = property.into();
+        if self.compact {
+            if property.value.is_empty() {
+                return Ok(());
+            }
+            compact_property(&mut property);
+        }
+        self.write_display(&property)
+    }
+
+    /// Writes a `VEVENT` component.
+    pub fn write_event(&mut self, event: &Event) -> io::Result<()> {
+        self.write_component_display(event)
+    }
+
+    /// Writes a `VTODO` component.
+    pub fn write_todo(&mut self, todo: &ToDo) -> io::Result<()> {
+        self.write_component_display(todo)
+    }
+
+    /// Writes a `VJOURNAL` component.
+    pub fn write_journal(&mut self, journal: &Journal) -> io::Result<()> {
+        self.write_component_display(journal)
+    }
+
+    /// Writes a `VFREEBUSY` component.
+    pub fn write_freebusy(&mut self, freebusy: &FreeBusy) -> io::Result<()> {
+        self.write_component_display(freebusy)
+    }
+
+    /// Writes a `VTIMEZONE` component.
+    pub fn write_timezone(&mut self, timezone: &TimeZone) -> io::Result<()> {
+        self.write_component_display(timezone)
+    }
+
+    /// Writes a `VAVAILABILITY` component.
+    pub fn write_availability(&mut self, availability: &Availability) -> io::Result<()> {
+        self.write_component_display(availability)
+    }
+
+    /// Writes a `VPOLL` component. `VPOLL` is experimental, see [`Poll`].
+    pub fn write_poll(&mut self, poll: &Poll) -> io::Result<()> {
+        self.write_component_display(poll)
+    }
+
+    /// Writes a [`Component`]. This should be only used for IANA/non-standard
+    /// components.
+    pub fn write_component(&mut self, component: &Component) -> io::Result<()> {
+        self.write_component_display(component)
+    }
+
+    /// Writes the end of the iCalendar object, flushes the buffer and returns
+    /// the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        let end = self.end();
+        self.write_display(&end)?;
+        match self.writer.take() {
+            Some(writer) => writer.into_inner().map_err(io::Error::from),
+            None => unreachable!("the writer is only taken on finish"),
+        }
+    }
+
+    fn write_component_display<'a, C>(&mut self, component: &C) -> io::Result<()>
+    where
+        C: fmt::Display + Clone + Into<Component<'a>>,
+    {
+        self.has_components = true;
+        if self.compact {
+            let mut component = component.clone().into();
+            compact_component(&mut component);
+            self.write_display(&component)
+        } else {
+            self.write_display(component)
+        }
+    }
+
+    fn end(&self) -> &'static str {
+        if self.options.trailing_line_break {
+            "END:VCALENDAR\r\n"
+        } else {
+            "END:VCALENDAR"
+        }
+    }
+
+    fn write_display(&mut self, content: &dyn fmt::Display) -> io::Result<()> {
+        match &mut self.writer {
+            Some(writer) => write!(writer, "{}", content),
+            None => unreachable!("the writer is only taken on finish"),
+        }
+    }
+}
+
+impl<W: Write> Drop for CalendarWriter<W> {
+    fn drop(&mut self) {
+        let end = self.end();
+        if let Some(writer) = &mut self.writer {
+            let _ = writer.write_all(end.as_bytes());
+        }
+    }
+}
+
+// Removes the properties without a value and the parameters that have their
+// default value, which does not change the meaning of the component.
+fn compact_component(component: &mut Component) {
+    component
+        .properties
+        .retain(|property| !property.value.is_empty());
+    component.properties.iter_mut().for_each(compact_property);
+    component
+        .subcomponents
+        .iter_mut()
+        .for_each(compact_component);
+}
+
+fn compact_property(property: &mut Property) {
+    let key = &property.key;
+    let defaults: Vec<_> = property
+        .parameters
+        .iter()
+        .filter(|(name, value)| is_default_parameter(key, name, value))
+        .map(|(name, _)| name.clone())
+        .collect();
+    for name in defaults {
+        property.parameters.remove(&name);
+    }
+}
+
+// Default values of parameters (see RFC5545 3.2.)
+const DEFAULT_PARAMETERS: [(&str, &str); 8] = [
+    ("CUTYPE", "INDIVIDUAL"),
+    ("ENCODING", "8BIT"),
+    ("FBTYPE", "BUSY"),
+    ("PARTSTAT", "NEEDS-ACTION"),
+    ("RELATED", "START"),
+    ("RELTYPE", "PARENT"),
+    ("ROLE", "REQ-PARTICIPANT"),
+    ("RSVP", "FALSE"),
+];
+
+// Default value types of properties that are not text (see RFC5545 3.8.)
+const DEFAULT_VALUE_TYPES: [(&str, &str); 26] = [
+    ("ATTACH", "URI"),
+    ("ATTENDEE", "CAL-ADDRESS"),
+    ("COMPLETED", "DATE-TIME"),
+    ("CREATED", "DATE-TIME"),
+    ("DTEND", "DATE-TIME"),
+    ("DTSTAMP", "DATE-TIME"),
+    ("DTSTART", "DATE-TIME"),
+    ("DUE", "DATE-TIME"),
+    ("DURATION", "DURATION"),
+    ("EXDATE", "DATE-TIME"),
+    ("FREEBUSY", "PERIOD"),
+    ("GEO", "FLOAT"),
+    ("LAST-MODIFIED", "DATE-TIME"),
+    ("ORGANIZER", "CAL-ADDRESS"),
+    ("PERCENT-COMPLETE", "INTEGER"),
+    ("PRIORITY", "INTEGER"),
+    ("RDATE", "DATE-TIME"),
+    ("RECURRENCE-ID", "DATE-TIME"),
+    ("REPEAT", "INTEGER"),
+    ("RRULE", "RECUR"),
+    ("SEQUENCE", "INTEGER"),
+    ("TRIGGER", "DURATION"),
+    ("TZOFFSETFROM", "UTC-OFFSET"),
+    ("TZOFFSETTO", "UTC-OFFSET"),
+    ("TZURL", "URI"),
+    ("URL", "URI"),
+];
+
+// Checks if a parameter can be omitted because it has the default value. The
+// value type of unknown properties is only known for the standard text
+// properties, so `VALUE=TEXT` is kept for the others.
+fn is_default_parameter(property: &str, name: &str, value: &str) -> bool {
+    if name.eq_ignore_ascii_case("VALUE") {
+        let value_type = DEFAULT_VALUE_TYPES
+            .iter()
+            .find(|(key, _)| property.eq_ignore_ascii_case(key))
+            .map(|(_, value_type)| *value_type)
+            .or_else(|| {
+                TEXT_PROPERTIES
+                    .iter()
+                    .find(|key| property.eq_ignore_ascii_case(key))
+                    .map(|_| "TEXT")
+            });
+        return value_type.map_or(false, |value_type| value.eq_ignore_ascii_case(value_type));
+    }
+    DEFAULT_PARAMETERS
+        .iter()
+        .any(|(key, default)| name.eq_ignore_ascii_case(key) && value.eq_ignore_ascii_case(default))
+}
+
+// Properties of RFC5545 that have the value type text
+const TEXT_PROPERTIES: [&str; 20] = [
+    "ACTION",
+    "CALSCALE",
+    "CATEGORIES",
+    "CLASS",
+    "COMMENT",
+    "CONTACT",
+    "DESCRIPTION",
+    "LOCATION",
+    "METHOD",
+    "PRODID",
+    "RELATED-TO",
+    "REQUEST-STATUS",
+    "RESOURCES",
+    "STATUS",
+    "SUMMARY",
+    "TRANSP",
+    "TZID",
+    "TZNAME",
+    "UID",
+    "VERSION",
+];
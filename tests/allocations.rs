@@ -0,0 +1,48 @@
+use ics::contentline::{fold, fold_into_string};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<F: FnOnce() -> String>(f: F) -> (usize, String) {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    let output = f();
+    (ALLOCATIONS.load(Ordering::SeqCst) - before, output)
+}
+
+#[test]
+fn fold_long_description() {
+    let description = "Lorem ipsum dolor sit amet. ".repeat(366);
+    assert!(description.len() > 10_000);
+
+    let (growing, expected) = count_allocations(|| {
+        let mut output = String::new();
+        fold(&mut output, &description).unwrap();
+        output
+    });
+    let (presized, folded) = count_allocations(|| fold_into_string(&description));
+    assert_eq!(folded, expected);
+    assert_eq!(presized, 1);
+    assert!(growing > presized);
+}
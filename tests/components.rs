@@ -1,9 +1,13 @@
 use ics::parameters::{FmtType, Related, Value};
 use ics::properties::{
-    Attach, Attendee, Categories, Class, Completed, Description, DtEnd, DtStart, Due, FreeBusyTime,
-    LastModified, Organizer, Priority, RRule, Status, Summary, Transp, Trigger, TzName, URL,
+    Attach, Attendee, BusyType, Categories, Class, Completed, Description, DtEnd, DtStart, Due,
+    FreeBusyTime, LastModified, Organizer, Priority, RRule, Status, Summary, Transp, Trigger,
+    TzName, URL,
+};
+use ics::{
+    escape_text, Alarm, Availability, Available, Daylight, Event, FreeBusy, Journal, Standard,
+    TimeZone, ToDo,
 };
-use ics::{escape_text, Alarm, Daylight, Event, FreeBusy, Journal, Standard, TimeZone, ToDo};
 
 #[test]
 fn event() {
@@ -31,6 +35,200 @@ fn event() {
     assert_eq!(event.to_string(), expected);
 }
 
+#[test]
+fn alarm_before() {
+    let expected = "BEGIN:VEVENT\r\n\
+                    UID:1\r\n\
+                    DTSTAMP:19970901T130000Z\r\n\
+                    BEGIN:VALARM\r\n\
+                    ACTION:DISPLAY\r\n\
+                    TRIGGER;RELATED=START:-PT15M\r\n\
+                    DESCRIPTION:Meeting\\, in 15 minutes\r\n\
+                    END:VALARM\r\n\
+                    END:VEVENT\r\n";
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.add_alarm_before(
+        ics::values::Duration::new().minutes(15),
+        "Meeting, in 15 minutes",
+    );
+    assert_eq!(event.to_string(), expected);
+    assert_eq!(event.validate(), Ok(()));
+}
+
+#[test]
+fn chained_event() {
+    let uid = "b68378cf-872d-44f1-9703-5e3725c56e71";
+    let mut expected = Event::new(uid, "19960704T120000Z");
+    expected.push(Summary::new("Networld+Interop Conference"));
+    expected.push(DtStart::new("19960918T143000Z"));
+
+    let event = Event::new(uid, "19960704T120000Z")
+        .with(Summary::new("Networld+Interop Conference"))
+        .with(DtStart::new("19960918T143000Z"));
+    assert_eq!(event, expected);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn event_now() {
+    let event = Event::now("b68378cf-872d-44f1-9703-5e3725c56e71").to_string();
+    let dtstamp = event.lines().nth(2).unwrap();
+    assert!(dtstamp.starts_with("DTSTAMP:"));
+    assert_eq!(dtstamp.len(), "DTSTAMP:19970901T130000Z".len());
+    assert!(dtstamp.ends_with('Z'));
+}
+
+#[test]
+fn participant() {
+    use ics::properties::{CalendarAddress, LocationType, ParticipantType};
+    use ics::{Participant, StructuredLocation, ValidationError};
+
+    let mut location = StructuredLocation::new("3");
+    location.push(LocationType::new("office"));
+    let mut participant = Participant::new("2", ParticipantType::speaker());
+    participant.push(CalendarAddress::new("mailto:jane@example.com"));
+    participant.add_location(location);
+    let mut event = Event::new("1", "20221005T133225Z");
+    event.add_participant(participant);
+    assert_eq!(event.validate(), Ok(()));
+
+    let expected = "BEGIN:VEVENT\r\n\
+                    UID:1\r\n\
+                    DTSTAMP:20221005T133225Z\r\n\
+                    BEGIN:VPARTICIPANT\r\n\
+                    UID:2\r\n\
+                    PARTICIPANT-TYPE:SPEAKER\r\n\
+                    CALENDAR-ADDRESS:mailto:jane@example.com\r\n\
+                    BEGIN:VLOCATION\r\n\
+                    UID:3\r\n\
+                    LOCATION-TYPE:office\r\n\
+                    END:VLOCATION\r\n\
+                    END:VPARTICIPANT\r\n\
+                    END:VEVENT\r\n";
+    assert_eq!(event.to_string(), expected);
+
+    let mut participant = Participant::new("2", ParticipantType::speaker());
+    participant.remove("PARTICIPANT-TYPE");
+    assert_eq!(
+        participant.validate(),
+        Err(ValidationError::MissingProperty {
+            component: "VPARTICIPANT",
+            property: "PARTICIPANT-TYPE",
+        })
+    );
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn event_location() {
+    use ics::properties::{Geo, Name};
+    use ics::{ICalendar, StructuredLocation};
+
+    let mut location = StructuredLocation::new("2");
+    location.push(Name::new("Conference Room"));
+    location.push(Geo::from_coords(37.386013, -122.082932));
+    location.push(URL::new("https://example.com/rooms/1"));
+    let mut event = Event::new("1", "20221005T133225Z");
+    event.add_location(location);
+    assert_eq!(event.validate(), Ok(()));
+
+    let expected = "BEGIN:VEVENT\r\n\
+                    UID:1\r\n\
+                    DTSTAMP:20221005T133225Z\r\n\
+                    BEGIN:VLOCATION\r\n\
+                    UID:2\r\n\
+                    NAME:Conference Room\r\n\
+                    GEO:37.386013;-122.082932\r\n\
+                    URL:https://example.com/rooms/1\r\n\
+                    END:VLOCATION\r\n\
+                    END:VEVENT\r\n";
+    assert_eq!(event.to_string(), expected);
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+    assert_eq!(ICalendar::parse(&calendar.to_string()).unwrap(), calendar);
+}
+
+#[test]
+fn uppercase_names() {
+    use ics::components::Property;
+
+    let mut property = Property::new("x-custom", "Mixed Case Value");
+    property.append(ics::parameters!("x-param" => "lower"; "Language" => "en"));
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(property);
+    event.push(Property::new(
+        "summary",
+        "A long summary that is folded, so the names are uppercase in folded lines too",
+    ));
+    let output = event.to_string();
+    assert!(output.contains("\r\nX-CUSTOM;LANGUAGE=en;X-PARAM=lower:Mixed Case Value\r\n"));
+    assert!(output.contains("\r\nSUMMARY:A long summary"));
+    assert!(!output.contains("\r\nsummary"));
+}
+
+#[test]
+fn poll() {
+    use ics::properties::{PollItemID, PollMode, PollProperties};
+    use ics::{ICalendar, Poll, ValidationError};
+
+    let expected = "BEGIN:VPOLL\r\n\
+                    UID:sched01-1234567890@example.com\r\n\
+                    DTSTAMP:20150403T142000Z\r\n\
+                    POLL-MODE:BASIC\r\n\
+                    POLL-PROPERTIES:DTSTART,DTEND\r\n\
+                    SUMMARY:What time should we meet?\r\n\
+                    BEGIN:VEVENT\r\n\
+                    UID:sched01-1234567890@example.com\r\n\
+                    DTSTAMP:20150403T142000Z\r\n\
+                    POLL-ITEM-ID:1\r\n\
+                    DTSTART:20150415T100000Z\r\n\
+                    BEGIN:VALARM\r\n\
+                    ACTION:AUDIO\r\n\
+                    TRIGGER:-PT15M\r\n\
+                    END:VALARM\r\n\
+                    END:VEVENT\r\n\
+                    BEGIN:VEVENT\r\n\
+                    UID:sched01-1234567890@example.com\r\n\
+                    DTSTAMP:20150403T142000Z\r\n\
+                    POLL-ITEM-ID:2\r\n\
+                    DTSTART:20150416T100000Z\r\n\
+                    END:VEVENT\r\n\
+                    END:VPOLL\r\n";
+
+    let mut poll = Poll::new("sched01-1234567890@example.com", "20150403T142000Z");
+    poll.push(PollMode::basic());
+    poll.push(PollProperties::new("DTSTART,DTEND"));
+    poll.push(Summary::new("What time should we meet?"));
+    for (id, start) in &[("1", "20150415T100000Z"), ("2", "20150416T100000Z")] {
+        let mut candidate = Event::new("sched01-1234567890@example.com", "20150403T142000Z");
+        candidate.push(PollItemID::new(*id));
+        candidate.push(DtStart::new(*start));
+        if *id == "1" {
+            candidate.add_alarm(Alarm::audio(Trigger::new("-PT15M")));
+        }
+        poll.add_candidate(candidate);
+    }
+    assert_eq!(poll.to_string(), expected);
+    assert_eq!(poll.candidates().count(), 2);
+    assert_eq!(poll.validate(), Ok(()));
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_poll(poll.clone());
+    assert_eq!(calendar.validate(), Ok(()));
+    assert_eq!(ICalendar::parse(&calendar.to_string()).unwrap(), calendar);
+
+    poll.remove("UID");
+    assert_eq!(
+        poll.validate(),
+        Err(ValidationError::MissingProperty {
+            component: "VPOLL",
+            property: "UID"
+        })
+    );
+}
+
 #[test]
 fn todo() {
     let expected = "BEGIN:VTODO\r\n\
@@ -62,9 +260,9 @@ fn journal() {
     DTSTAMP:19970901T130000Z\r\n\
     DTSTART;VALUE=DATE:19970317\r\n\
     SUMMARY:Staff meeting minutes\r\n\
-    DESCRIPTION:1. Staff meeting: Participants include Joe\\, Lisa\\, and Bob. Au\r\n rora project plans were reviewed. There is currently no budget reserves for\r\n  this project. Lisa will escalate to management. Next meeting on Tuesday.\\n\
-    \r\n 2. Telephone Conference: ABC Corp. sales representative called to discuss n\r\n ew printer. Promised to get us a demo by Friday.\\n\
-    3. Henry Miller (Handsoff\r\n  Insurance): Car was totaled by tree. Is looking into a loaner car. 555-232\r\n 3 (tel).\r\n\
+    DESCRIPTION:1. Staff meeting: Participants include Joe\\, Lisa\\, and Bob. Au\r\n rora project plans were reviewed. There is currently no budget reserves fo\r\n r this project. Lisa will escalate to management. Next meeting on Tuesday.\r\n \\n\
+    2. Telephone Conference: ABC Corp. sales representative called to discus\r\n s new printer. Promised to get us a demo by Friday.\\n\
+    3. Henry Miller (Hand\r\n soff Insurance): Car was totaled by tree. Is looking into a loaner car. 55\r\n 5-2323 (tel).\r\n\
     END:VJOURNAL\r\n";
 
     let mut journal = Journal::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19970901T130000Z");
@@ -79,6 +277,25 @@ fn journal() {
     assert_eq!(journal.to_string(), expected);
 }
 
+#[test]
+fn journal_entries() {
+    let uid = "b68378cf-872d-44f1-9703-5e3725c56e71";
+    let mut expected = Journal::new(uid, "19970901T130000Z");
+    expected.push(Description::new("1. Staff meeting: Joe\\, Lisa\\, and Bob"));
+    expected.push(Description::new("2. Telephone Conference"));
+
+    let journal = Journal::with_entries(
+        uid,
+        "19970901T130000Z",
+        &[
+            "1. Staff meeting: Joe, Lisa, and Bob",
+            "2. Telephone Conference",
+        ],
+    );
+    assert_eq!(journal, expected);
+    assert_eq!(journal.validate(), Ok(()));
+}
+
 #[test]
 fn freebusy() {
     let expected = "BEGIN:VFREEBUSY\r\n\
@@ -138,12 +355,91 @@ fn time() {
     assert_eq!(timezone.to_string(), expected);
 }
 
+#[test]
+fn fixed_offset_time() {
+    let expected = "BEGIN:VTIMEZONE\r\n\
+                    TZID:Asia/Kolkata\r\n\
+                    BEGIN:STANDARD\r\n\
+                    DTSTART:19700101T000000\r\n\
+                    TZOFFSETFROM:+0530\r\n\
+                    TZOFFSETTO:+0530\r\n\
+                    END:STANDARD\r\n\
+                    END:VTIMEZONE\r\n";
+    let timezone = TimeZone::fixed_offset("Asia/Kolkata", 330);
+    assert_eq!(timezone.to_string(), expected);
+    assert_eq!(timezone.validate(), Ok(()));
+}
+
+#[test]
+fn preset_time() {
+    let expected = "BEGIN:VTIMEZONE\r\n\
+                    TZID:America/New_York\r\n\
+                    BEGIN:STANDARD\r\n\
+                    DTSTART:20071104T020000\r\n\
+                    TZOFFSETFROM:-0400\r\n\
+                    TZOFFSETTO:-0500\r\n\
+                    RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n\
+                    TZNAME:EST\r\n\
+                    END:STANDARD\r\n\
+                    BEGIN:DAYLIGHT\r\n\
+                    DTSTART:20070311T020000\r\n\
+                    TZOFFSETFROM:-0500\r\n\
+                    TZOFFSETTO:-0400\r\n\
+                    RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n\
+                    TZNAME:EDT\r\n\
+                    END:DAYLIGHT\r\n\
+                    END:VTIMEZONE\r\n";
+    assert_eq!(TimeZone::us_eastern().to_string(), expected);
+    assert_eq!(TimeZone::central_europe().validate(), Ok(()));
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn iana_time() {
+    let expected = "BEGIN:VTIMEZONE\r\n\
+                    TZID:Europe/Berlin\r\n\
+                    BEGIN:DAYLIGHT\r\n\
+                    DTSTART:20240331T020000\r\n\
+                    TZOFFSETFROM:+0100\r\n\
+                    TZOFFSETTO:+0200\r\n\
+                    RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+                    TZNAME:CEST\r\n\
+                    END:DAYLIGHT\r\n\
+                    BEGIN:STANDARD\r\n\
+                    DTSTART:20241027T030000\r\n\
+                    TZOFFSETFROM:+0200\r\n\
+                    TZOFFSETTO:+0100\r\n\
+                    RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\n\
+                    TZNAME:CET\r\n\
+                    END:STANDARD\r\n\
+                    END:VTIMEZONE\r\n";
+    let timezone = TimeZone::from_tz(chrono_tz::Europe::Berlin, 2024);
+    assert_eq!(timezone.to_string(), expected);
+
+    let expected = "BEGIN:VTIMEZONE\r\n\
+                    TZID:Asia/Tokyo\r\n\
+                    BEGIN:STANDARD\r\n\
+                    DTSTART:19700101T000000\r\n\
+                    TZOFFSETFROM:+0900\r\n\
+                    TZOFFSETTO:+0900\r\n\
+                    TZNAME:JST\r\n\
+                    END:STANDARD\r\n\
+                    END:VTIMEZONE\r\n";
+    let timezone = TimeZone::from_tz(chrono_tz::Asia::Tokyo, 2024);
+    assert_eq!(timezone.to_string(), expected);
+
+    let timezone = TimeZone::from_tz(chrono_tz::America::New_York, 2024).to_string();
+    assert!(timezone.contains("DTSTART:20240310T020000\r\n"));
+    assert!(timezone.contains("RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\n"));
+    assert!(timezone.contains("RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\n"));
+}
+
 #[test]
 fn alarm() {
     let expected = "BEGIN:VALARM\r\n\
                     ACTION:EMAIL\r\n\
                     TRIGGER;RELATED=END:-P2D\r\n\
-                    DESCRIPTION:A draft agenda needs to be sent out to the attendees to the wee\r\n kly managers meeting (MGR-LIST). Attached is a pointer the document templat\r\n e for the agenda file.\r\n\
+                    DESCRIPTION:A draft agenda needs to be sent out to the attendees to the wee\r\n kly managers meeting (MGR-LIST). Attached is a pointer the document templa\r\n te for the agenda file.\r\n\
                     SUMMARY:*** REMINDER: SEND AGENDA FOR WEEKLY STAFF MEETING ***\r\n\
                     ATTENDEE:mailto:john_doe@example.com\r\n\
                     ATTACH;FMTTYPE=application/msword:http://example.com/templates/agenda.doc\r\n\
@@ -159,3 +455,118 @@ fn alarm() {
 
     assert_eq!(alarm.to_string(), expected);
 }
+
+#[test]
+fn remove_and_set() {
+    let expected = "BEGIN:VEVENT\r\n\
+                    UID:b68378cf-872d-44f1-9703-5e3725c56e71\r\n\
+                    DTSTAMP:19970901T130000Z\r\n\
+                    STATUS:CONFIRMED\r\n\
+                    SUMMARY:Annual Employee Review\r\n\
+                    END:VEVENT\r\n";
+
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19970901T130000Z");
+    event.push(Status::tentative());
+    event.push(Categories::new("BUSINESS"));
+    event.push(Categories::new("HUMAN RESOURCES"));
+    event.push(Summary::new("Annual Employee Review"));
+    event.push(Status::cancelled());
+    event.set(Status::confirmed());
+    assert!(event.remove("categories"));
+    assert!(!event.remove("CATEGORIES"));
+    assert_eq!(event.to_string(), expected);
+}
+
+#[test]
+fn get() {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19970901T130000Z");
+    event.push(Summary::new("Annual Employee Review"));
+    event.push(Categories::new("BUSINESS"));
+    event.push(Categories::new("HUMAN RESOURCES"));
+
+    let summary = event.get("summary").unwrap();
+    assert_eq!(summary.key(), "SUMMARY");
+    assert_eq!(summary.value(), "Annual Employee Review");
+    assert!(event.get("DTSTART").is_none());
+    let categories: Vec<_> = event.get_all("CATEGORIES").map(|p| p.value()).collect();
+    assert_eq!(categories, ["BUSINESS", "HUMAN RESOURCES"]);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn random_uid() {
+    let event = Event::with_random_uid("19970901T130000Z");
+    assert_eq!(event.get("UID").unwrap().value().len(), 36);
+    assert_eq!(event.get("DTSTAMP").unwrap().value(), "19970901T130000Z");
+}
+
+#[test]
+fn availability() {
+    let expected = "BEGIN:VAVAILABILITY\r\n\
+                    UID:0428C7D2-688E-4D2E-AC52-CD112E2469DF\r\n\
+                    DTSTAMP:20111005T133225Z\r\n\
+                    BUSYTYPE:BUSY\r\n\
+                    BEGIN:AVAILABLE\r\n\
+                    UID:34EDA59B-6BB1-4E94-A66C-64999089C0AF\r\n\
+                    DTSTAMP:20111005T133225Z\r\n\
+                    DTSTART:20111003T090000Z\r\n\
+                    DTEND:20111003T170000Z\r\n\
+                    END:AVAILABLE\r\n\
+                    END:VAVAILABILITY\r\n";
+
+    let mut availability =
+        Availability::new("0428C7D2-688E-4D2E-AC52-CD112E2469DF", "20111005T133225Z");
+    availability.push(BusyType::busy());
+    let mut available = Available::new(
+        "34EDA59B-6BB1-4E94-A66C-64999089C0AF",
+        "20111005T133225Z",
+        DtStart::new("20111003T090000Z"),
+    );
+    available.push(DtEnd::new("20111003T170000Z"));
+    availability.add_available(available);
+    assert_eq!(availability.to_string(), expected);
+}
+
+#[test]
+fn clone_event() {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19970901T130000Z");
+    event.push(Summary::new("Annual Employee Review"));
+    let original = event.to_string();
+
+    let mut clone = event.clone();
+    assert_eq!(clone, event);
+    clone.set(Summary::new("Quarterly Review"));
+    clone.add_alarm(Alarm::audio(Trigger::new("-PT15M")));
+    assert_ne!(clone, event);
+    assert_eq!(event.to_string(), original);
+}
+
+#[test]
+fn common_traits() {
+    use ics::components::{Component, Parameter, Property};
+    use ics::parameters::PartStat;
+    use ics::{Availability, Available, CalendarComponent, ICalendar, ValidationError};
+    use std::fmt::Debug;
+    use std::hash::Hash;
+
+    fn assert_traits<T: Debug + Clone + PartialEq + Eq + Hash + Ord>() {}
+    assert_traits::<ICalendar>();
+    assert_traits::<CalendarComponent>();
+    assert_traits::<Event>();
+    assert_traits::<ToDo>();
+    assert_traits::<Journal>();
+    assert_traits::<FreeBusy>();
+    assert_traits::<TimeZone>();
+    assert_traits::<Standard>();
+    assert_traits::<Daylight>();
+    assert_traits::<Alarm>();
+    assert_traits::<Availability>();
+    assert_traits::<Available>();
+    assert_traits::<Component>();
+    assert_traits::<Property>();
+    assert_traits::<Parameter>();
+    assert_traits::<Summary>();
+    assert_traits::<PartStat>();
+    assert_traits::<Related>();
+    assert_traits::<ValidationError>();
+}
@@ -1,9 +1,12 @@
 use ics::parameters::{FmtType, PartStat};
 use ics::properties::{
-    Attach, Attendee, Categories, Description, DtEnd, DtStart, Due, Duration, Organizer, Repeat,
-    Sequence, Status, Summary, Trigger,
+    Action, Attach, Attendee, Categories, Completed, Description, DtEnd, DtStart, Due, Duration,
+    Organizer, PercentComplete, RecurrenceID, Repeat, Sequence, Status, Summary, Trigger,
+};
+use ics::{
+    escape_text, Alarm, CalendarComponent, Event, ICalendar, Standard, TimeZone, ToDo,
+    ValidationError,
 };
-use ics::{escape_text, Alarm, Event, ICalendar, ToDo};
 
 #[test]
 fn icalendar_event() {
@@ -90,3 +93,1022 @@ fn icalendar_todo() {
 
     assert_eq!(calendar.to_string(), expected);
 }
+
+#[test]
+fn validate() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new(
+        "b68378cf-872d-44f1-9703-5e3725c56e71",
+        "19960704T120000Z",
+    ));
+    assert_eq!(calendar.validate(), Ok(()));
+
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:b68378cf-872d-44f1-9703-5e3725c56e71\r\n\
+                 END:VEVENT\r\n\
+                 END:VCALENDAR\r\n";
+    let error = ICalendar::parse(input).unwrap().validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::MissingProperty {
+            component: "VEVENT",
+            property: "DTSTAMP"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VEVENT is missing the required DTSTAMP property"
+    );
+}
+
+#[test]
+fn validate_timezone() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VTIMEZONE\r\n\
+                 TZID:America/New_York\r\n\
+                 END:VTIMEZONE\r\n\
+                 END:VCALENDAR\r\n";
+    let error = ICalendar::parse(input).unwrap().validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::MissingComponent {
+            component: "VTIMEZONE",
+            subcomponent: "STANDARD or DAYLIGHT"
+        }
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_matches_to_string() {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    event.push(Description::new(
+        "A description that is long enough to be folded into more than one content line.",
+    ));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+
+    let mut buffer = Vec::new();
+    calendar.write(&mut buffer).unwrap();
+    assert_eq!(String::from_utf8(buffer).unwrap(), calendar.to_string());
+    assert!(calendar.to_string().ends_with("END:VCALENDAR\r\n"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_multibytes() {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    event.push(Summary::new(
+        "Content lines shouldn't be folded in the middle of a UTF-8 character! 老虎.",
+    ));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+
+    let mut buffer = Vec::new();
+    calendar.write(&mut buffer).unwrap();
+    let content = String::from_utf8(buffer).unwrap();
+    assert_eq!(content, calendar.to_string());
+}
+
+#[test]
+fn iterate_components() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "19960704T120000Z"));
+    calendar.add_todo(ToDo::new("2", "19960704T120000Z"));
+    calendar.add_event(Event::new("3", "19960704T120000Z"));
+
+    assert_eq!(calendar.components().count(), 3);
+    assert_eq!(calendar.events().count(), 2);
+    for event in calendar.events_mut() {
+        event.push(Summary::new("Meeting"));
+    }
+    assert!(calendar
+        .events()
+        .all(|event| event.to_string().contains("SUMMARY:Meeting\r\n")));
+    match calendar.components().nth(1) {
+        Some(CalendarComponent::ToDo(todo)) => assert!(!todo.to_string().contains("SUMMARY")),
+        _ => panic!("expected a to-do"),
+    };
+}
+
+#[test]
+fn validate_alarm() {
+    let trigger = Trigger::new("-PT15M");
+    assert_eq!(Alarm::audio(trigger.clone()).validate(), Ok(()));
+    let display = Alarm::display(trigger.clone(), Description::new("Meeting"));
+    assert_eq!(display.validate(), Ok(()));
+
+    let mut email = Alarm::email(
+        trigger.clone(),
+        Description::new("A meeting starts soon."),
+        Summary::new("Meeting"),
+    );
+    assert_eq!(
+        email.validate(),
+        Err(ValidationError::MissingProperty {
+            component: "VALARM",
+            property: "ATTENDEE"
+        })
+    );
+    email.push(Attendee::new("mailto:john_doe@example.com"));
+    assert_eq!(email.validate(), Ok(()));
+
+    let display = Alarm::new(Action::display(), trigger);
+    assert_eq!(
+        display.validate(),
+        Err(ValidationError::MissingProperty {
+            component: "VALARM",
+            property: "DESCRIPTION"
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn calendar_writer() {
+    use ics::CalendarWriter;
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.push(Categories::new("WORK"));
+    let mut writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs").unwrap();
+    writer.write_property(Categories::new("WORK")).unwrap();
+    for uid in 0..100 {
+        let mut event = Event::new(uid.to_string(), "19960704T120000Z");
+        event.push(Description::new(
+            "A description that is long enough to be folded into several content lines.",
+        ));
+        writer.write_event(&event).unwrap();
+        calendar.add_event(event);
+    }
+    let error = writer.write_property(Categories::new("HOME")).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    let output = writer.finish().unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), calendar.to_string());
+
+    let mut output = Vec::new();
+    drop(CalendarWriter::new(&mut output, "2.0", "ics-rs").unwrap());
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        ICalendar::new("2.0", "ics-rs").to_string()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn trailing_line_break() {
+    use ics::{CalendarWriter, WriteOptions};
+
+    let calendar = ICalendar::new("2.0", "ics-rs").to_string();
+    assert!(calendar.ends_with("\r\nEND:VCALENDAR\r\n"));
+    let writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs").unwrap();
+    assert_eq!(writer.finish().unwrap(), calendar.as_bytes());
+
+    let writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs")
+        .unwrap()
+        .without_trailing_line_break();
+    let output = writer.finish().unwrap();
+    assert!(output.ends_with(b"\r\nEND:VCALENDAR"));
+    assert!(ICalendar::parse(std::str::from_utf8(&output).unwrap()).is_ok());
+
+    let calendar = ICalendar::new("2.0", "ics-rs");
+    let options = WriteOptions::default().trailing_line_break(false);
+    let mut written = Vec::new();
+    calendar.write_with_options(&mut written, &options).unwrap();
+    assert_eq!(written, output);
+    let options = options.fold_width(76);
+    let mut written = Vec::new();
+    calendar.write_with_options(&mut written, &options).unwrap();
+    assert_eq!(written, output);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_writer() {
+    use ics::parameters::{Value, CN, RSVP};
+    use ics::properties::{Location, Transp};
+    use ics::CalendarWriter;
+
+    // Events like the ones that calendar services export, which write empty
+    // properties and parameters with default values
+    let events: Vec<_> = (0..50)
+        .map(|uid| {
+            let mut event = Event::new(format!("{}@example.com", uid), "20240102T093000Z");
+            let mut dtstart = DtStart::new("20240108T090000Z");
+            dtstart.add(Value::DATE_TIME);
+            event.push(dtstart);
+            event.push(DtEnd::new("20240108T100000Z"));
+            event.push(Summary::new("Weekly planning"));
+            event.push(Description::new(""));
+            event.push(Location::new(""));
+            for name in &["Jane", "John", "Mary"] {
+                let mut attendee = Attendee::new(format!("mailto:{}@example.com", name));
+                attendee.append(ics::parameters!(
+                    "CUTYPE" => "INDIVIDUAL";
+                    "ROLE" => "REQ-PARTICIPANT";
+                    "PARTSTAT" => "NEEDS-ACTION"
+                ));
+                attendee.add(RSVP::False);
+                attendee.add(CN::new(*name));
+                event.push(attendee);
+            }
+            event.push(Sequence::new("0"));
+            event.push(Transp::opaque());
+            event
+        })
+        .collect();
+    let write = |compact: bool| {
+        let mut writer = CalendarWriter::new(Vec::new(), "2.0", "ics-rs").unwrap();
+        if compact {
+            writer = writer.compact();
+        }
+        for event in &events {
+            writer.write_event(event).unwrap();
+        }
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    };
+    let output = write(false);
+    let compact = write(true);
+    // The compact output is less than two thirds of the size.
+    assert!(compact.len() * 3 < output.len() * 2);
+    assert!(compact.split("\r\n").all(|line| line.len() <= 75));
+
+    let calendar = ICalendar::parse(&compact).unwrap();
+    let event = calendar.events().next().unwrap();
+    assert_eq!(
+        event.get("DTSTART").unwrap().to_string(),
+        "DTSTART:20240108T090000Z\r\n"
+    );
+    assert!(event.get("LOCATION").is_none());
+    assert_eq!(
+        event.get("ATTENDEE").unwrap().to_string(),
+        "ATTENDEE;CN=Jane:mailto:Jane@example.com\r\n"
+    );
+}
+
+#[test]
+fn validate_todo() {
+    let mut todo = ToDo::new("1", "19980130T134500Z");
+    todo.push(PercentComplete::from_percent(40));
+    assert_eq!(todo.validate(), Ok(()));
+    todo.push(Status::completed());
+    let error = todo.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::Inconsistent {
+            component: "VTODO",
+            property: "STATUS",
+            other: "PERCENT-COMPLETE"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VTODO has inconsistent STATUS and PERCENT-COMPLETE properties"
+    );
+    todo.set(PercentComplete::from_percent(200));
+    assert_eq!(
+        todo.validate(),
+        Err(ValidationError::MissingProperty {
+            component: "VTODO",
+            property: "COMPLETED"
+        })
+    );
+    todo.push(Completed::new("19980131T100000Z"));
+    assert_eq!(todo.validate(), Ok(()));
+
+    todo.set(PercentComplete::new("150"));
+    assert_eq!(
+        todo.validate(),
+        Err(ValidationError::InvalidValue {
+            component: "VTODO",
+            property: "PERCENT-COMPLETE"
+        })
+    );
+}
+
+#[test]
+fn validate_todo_due() {
+    use ics::parameters::TzIDParam;
+
+    let mut todo = ToDo::new("1", "19980130T134500Z");
+    todo.push(DtStart::new("19980401T090000Z"));
+    todo.push(Due::new("19980415T000000Z"));
+    assert_eq!(todo.validate(), Ok(()));
+
+    todo.push(Duration::new("PT1H"));
+    assert_eq!(
+        todo.validate(),
+        Err(ValidationError::Exclusive {
+            component: "VTODO",
+            property: "DUE",
+            other: "DURATION"
+        })
+    );
+    todo.remove("DURATION");
+
+    todo.set(Due::date("19980415"));
+    assert_eq!(
+        todo.validate(),
+        Err(ValidationError::Inconsistent {
+            component: "VTODO",
+            property: "DTSTART",
+            other: "DUE"
+        })
+    );
+
+    todo.set(Due::new("19980315T000000Z"));
+    let error = todo.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::Inconsistent {
+            component: "VTODO",
+            property: "DTSTART",
+            other: "DUE"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VTODO has inconsistent DTSTART and DUE properties"
+    );
+
+    // Local times in different time zones are not compared.
+    let mut due = Due::new("19980315T000000");
+    due.add(TzIDParam::new("America/New_York"));
+    todo.set(due);
+    todo.set(DtStart::new("19980401T090000"));
+    assert_eq!(todo.validate(), Ok(()));
+}
+
+#[test]
+fn version_2_0() {
+    use ics::properties::Version;
+    use ics::ICalendarBuilder;
+
+    assert_eq!(Version::v2_0(), Version::new("2.0"));
+    assert_eq!(Version::default(), Version::v2_0());
+    let calendar = ICalendar::new(Version::v2_0(), "ics-rs");
+    assert_eq!(calendar.version(), Some("2.0"));
+    assert_eq!(
+        ICalendarBuilder::new("ics-rs")
+            .version(Version::v2_0())
+            .build(),
+        calendar
+    );
+}
+
+#[test]
+fn icalendar_builder() {
+    use ics::properties::{CalScale, Method, XWrCalName};
+    use ics::ICalendarBuilder;
+
+    let mut expected = ICalendar::new("2.0", "ics-rs");
+    expected.push(Method::request());
+    expected.push(CalScale::new("GREGORIAN"));
+    expected.push(XWrCalName::new("Holidays"));
+    expected.add_timezone(TimeZone::standard(
+        "Europe/Berlin",
+        Standard::new("19701025T030000", "+0200", "+0100"),
+    ));
+
+    let calendar = ICalendarBuilder::new("-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN")
+        .prod_id("ics-rs")
+        .method(Method::request())
+        .calscale("GREGORIAN")
+        .name("Holidays")
+        .timezone(TimeZone::standard(
+            "Europe/Berlin",
+            Standard::new("19701025T030000", "+0200", "+0100"),
+        ))
+        .build();
+    assert_eq!(calendar, expected);
+    let calendar = ICalendarBuilder::new("ics-rs").version("2.1").build();
+    assert_eq!(calendar, ICalendar::new("2.1", "ics-rs"));
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn rfc7986_calendar_properties() {
+    use ics::properties::{LastModified, Name, RefreshInterval, Source, URL};
+    use ics::values::Duration;
+
+    let expected = "BEGIN:VCALENDAR\r\n\
+                    VERSION:2.0\r\n\
+                    PRODID:ics-rs\r\n\
+                    NAME:Company Vacation Days\r\n\
+                    DESCRIPTION:The calendar of company vacation days\r\n\
+                    REFRESH-INTERVAL;VALUE=DURATION:P1W\r\n\
+                    SOURCE;VALUE=URI:http://example.com/holidays.ics\r\n\
+                    URL:http://example.com/holidays\r\n\
+                    LAST-MODIFIED:20220101T000000Z\r\n\
+                    BEGIN:VEVENT\r\n\
+                    UID:1\r\n\
+                    DTSTAMP:20220101T000000Z\r\n\
+                    END:VEVENT\r\n\
+                    END:VCALENDAR\r\n";
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "20220101T000000Z"));
+    calendar.push(Name::new("Company Vacation Days"));
+    calendar.push(Description::text("The calendar of company vacation days"));
+    calendar.push(RefreshInterval::from_duration(Duration::new().weeks(1)));
+    calendar.push(Source::new("http://example.com/holidays.ics"));
+    calendar.push(URL::new("http://example.com/holidays"));
+    calendar.push(LastModified::new("20220101T000000Z"));
+    assert_eq!(calendar.to_string(), expected);
+    assert_eq!(ICalendar::parse(expected).unwrap(), calendar);
+}
+
+#[test]
+fn timezone_lookup() {
+    use ics::parameters::TzIDParam;
+
+    let mut dtstart = DtStart::new("19980119T020000");
+    dtstart.add(TzIDParam::new("America/New_York"));
+    let mut dtend = DtEnd::new("19980119T030000");
+    dtend.add(TzIDParam::new("america/new_york"));
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(dtstart);
+    event.push(dtend);
+    let mut due = Due::new("19980415T000000");
+    due.add(TzIDParam::new("Europe/Berlin"));
+    let mut todo = ToDo::new("2", "19970901T130000Z");
+    todo.push(due);
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+    calendar.add_todo(todo);
+    assert_eq!(
+        calendar.missing_timezones(),
+        ["America/New_York", "Europe/Berlin"]
+    );
+
+    let timezone = TimeZone::standard(
+        "America/New_York",
+        Standard::new("19671029T020000", "-0400", "-0500"),
+    );
+    calendar.add_timezone(timezone.clone());
+    assert_eq!(calendar.timezone("AMERICA/NEW_YORK"), Some(&timezone));
+    assert_eq!(calendar.timezone("Europe/Berlin"), None);
+    assert_eq!(calendar.missing_timezones(), ["Europe/Berlin"]);
+}
+
+#[test]
+fn timezone_lookup_in_locations() {
+    use ics::components::Property;
+    use ics::parameters::TzIDParam;
+    use ics::properties::ParticipantType;
+    use ics::{Participant, StructuredLocation};
+
+    let mut opens = Property::new("X-OPENS", "19980119T080000");
+    opens.add(TzIDParam::new("America/New_York"));
+    let mut location = StructuredLocation::new("2");
+    location.push(opens);
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.add_location(location);
+
+    let mut closes = Property::new("X-CLOSES", "19980119T180000");
+    closes.add(TzIDParam::new("Europe/Berlin"));
+    let mut location = StructuredLocation::new("4");
+    location.push(closes);
+    let mut participant = Participant::new("3", ParticipantType::speaker());
+    participant.add_location(location);
+    event.add_participant(participant);
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+    assert_eq!(
+        calendar.missing_timezones(),
+        ["Europe/Berlin", "America/New_York"]
+    );
+}
+
+#[test]
+fn header_accessors() {
+    let mut calendar = ICalendar::new("2.0", "-//ABC Corporation//NONSGML My Product//EN");
+    assert_eq!(calendar.version(), Some("2.0"));
+    assert_eq!(
+        calendar.prod_id(),
+        Some("-//ABC Corporation//NONSGML My Product//EN")
+    );
+    calendar.remove("PRODID");
+    assert_eq!(calendar.prod_id(), None);
+
+    let input = "BEGIN:VCALENDAR\r\nPRODID:ics-rs\r\nVERSION:2.0\r\nEND:VCALENDAR\r\n";
+    let calendar = ICalendar::parse(input).unwrap();
+    assert_eq!(calendar.version(), Some("2.0"));
+    assert_eq!(calendar.prod_id(), Some("ics-rs"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn serialized_len() {
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    event.push(Description::new(escape_text(
+        "Networld+Interop Conference and Exhibit\n\
+         Atlanta World Congress Center\n\
+         Atlanta, Georgia, 老虎",
+    )));
+    let mut calendar = ICalendar::new("2.0", "-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN");
+    calendar.add_event(event);
+
+    let mut output = Vec::new();
+    calendar.write(&mut output).unwrap();
+    assert_eq!(calendar.serialized_len(), output.len());
+    assert_eq!(calendar.to_vec(), output);
+    assert_eq!(calendar.to_vec(), calendar.to_string().into_bytes());
+}
+
+#[test]
+fn max_unfolded_length() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    assert_eq!(calendar.max_unfolded_length(), "BEGIN:VCALENDAR".len());
+    let description = "A description that is longer than a single content line, so it is folded.";
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(Description::new(description));
+    calendar.add_event(event);
+    assert_eq!(
+        calendar.max_unfolded_length(),
+        "DESCRIPTION:".len() + description.len()
+    );
+    assert_eq!(ics::contentline::longest_line(&calendar.to_string()), 75);
+}
+
+#[test]
+fn validate_control_characters() {
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(Summary::new("Tab\tand line\nbreak"));
+    assert_eq!(event.validate(), Ok(()));
+    event.push(Description::new("Null\0"));
+    let error = event.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::ControlCharacter {
+            component: "VEVENT",
+            property: "DESCRIPTION".to_string(),
+            offset: 4
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VEVENT has a control character in the DESCRIPTION property at byte 4"
+    );
+
+    let mut alarm = Alarm::display(Trigger::new("-PT15M"), Description::new("Vertical\x0Btab"));
+    assert!(alarm.validate().is_err());
+    alarm.set(Description::new("Delete\x7F"));
+    assert!(alarm.validate().is_err());
+}
+
+#[test]
+fn validate_double_quotes() {
+    use ics::parameters::CN;
+    use ics::properties::Attendee;
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    let mut attendee = Attendee::new("mailto:jdoe@example.com");
+    attendee.add(CN::new("\"Doe, John\""));
+    event.push(attendee);
+    assert_eq!(event.validate(), Ok(()));
+
+    // The constructors of the parameters reject double quotes in debug builds.
+    let mut attendee = Attendee::new("mailto:jdoe@example.com");
+    attendee.append(ics::parameters!("CN" => "Doe \"J\", John"));
+    event.set(attendee);
+    let error = event.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::DoubleQuote {
+            component: "VEVENT",
+            property: "ATTENDEE".to_string(),
+            parameter: "CN".to_string()
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VEVENT has a double quote in the CN parameter of the ATTENDEE property"
+    );
+
+    let mut attendee = Attendee::new("mailto:jdoe@example.com");
+    attendee.append(ics::parameters!(
+        "DELEGATED-TO" => "\"mailto:a@example.com\",\"mailto:\"b\"@example.com\""
+    ));
+    event.set(attendee);
+    assert!(event.validate().is_err());
+}
+
+#[test]
+fn validate_unique_uids() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "19970901T130000Z"));
+    let mut instance = Event::new("1", "19970901T130000Z");
+    instance.push(RecurrenceID::new("19970902T130000Z"));
+    calendar.add_event(instance.clone());
+    calendar.add_todo(ToDo::new("2", "19970901T130000Z"));
+    assert_eq!(calendar.validate(), Ok(()));
+
+    calendar.add_event(instance);
+    let error = calendar.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::DuplicateUID {
+            uid: "1".to_string()
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VCALENDAR contains several components with the UID 1"
+    );
+}
+
+#[test]
+fn validate_event_end() {
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(DtStart::new("19970903T163000Z"));
+    event.push(DtEnd::new("19970903T190000Z"));
+    assert_eq!(event.validate(), Ok(()));
+    event.push(Duration::new("PT1H"));
+    let error = event.validate().unwrap_err();
+    assert_eq!(
+        error,
+        ValidationError::Exclusive {
+            component: "VEVENT",
+            property: "DTEND",
+            other: "DURATION"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "VEVENT must not contain both the DTEND and DURATION properties"
+    );
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(DtStart::date("19970903"));
+    event.push(DtEnd::date("19970904"));
+    assert_eq!(event.validate(), Ok(()));
+    event.set(DtEnd::new("19970904T000000Z"));
+    assert_eq!(
+        event.validate(),
+        Err(ValidationError::Inconsistent {
+            component: "VEVENT",
+            property: "DTSTART",
+            other: "DTEND"
+        })
+    );
+    event.set(DtStart::new("19970903T163000Z"));
+    event.set(DtEnd::date("19970904"));
+    assert!(event.validate().is_err());
+}
+
+#[test]
+fn sorted() {
+    let mut first = Event::new("1", "19970901T130000Z");
+    first.push(Summary::new("Meeting"));
+    first.push(Categories::new("WORK"));
+    first.push(Categories::new("PROJECT"));
+    first.push(DtStart::new("19970903T163000Z"));
+    let mut a = ICalendar::new("2.0", "ics-rs");
+    a.add_event(first);
+
+    let mut second = Event::new("1", "19970901T130000Z");
+    second.push(DtStart::new("19970903T163000Z"));
+    second.push(Categories::new("PROJECT"));
+    second.push(Summary::new("Meeting"));
+    second.push(Categories::new("WORK"));
+    let mut b = ICalendar::new("2.0", "ics-rs");
+    b.add_event(second);
+
+    assert_ne!(a.to_string(), b.to_string());
+    let a = a.sorted();
+    assert_eq!(a.to_string(), b.sorted().to_string());
+    assert!(a.to_string().contains(
+        "UID:1\r\n\
+         DTSTAMP:19970901T130000Z\r\n\
+         CATEGORIES:PROJECT\r\n\
+         CATEGORIES:WORK\r\n\
+         DTSTART:19970903T163000Z\r\n\
+         SUMMARY:Meeting\r\n"
+    ));
+}
+
+#[test]
+fn append_and_merge() {
+    let timezone = || {
+        TimeZone::standard(
+            "Europe/Berlin",
+            Standard::new("19701025T030000", "+0200", "+0100"),
+        )
+    };
+    let mut a = ICalendar::new("2.0", "ics-rs");
+    a.add_timezone(timezone());
+    a.add_event(Event::new("1", "19970901T130000Z"));
+
+    let mut b = ICalendar::new("2.0", "other");
+    b.add_timezone(timezone());
+    b.add_event(Event::new("2", "19970901T130000Z"));
+    let mut c = b.clone();
+
+    a.append(b);
+    assert_eq!(a.components().count(), 3);
+    assert_eq!(a.get("PRODID").unwrap().value(), "ics-rs");
+
+    let mut instance = Event::new("1", "19970901T130000Z");
+    instance.push(RecurrenceID::new("19970902T130000Z"));
+    c.add_event(instance);
+    c.add_event(Event::new("3", "19970901T130000Z"));
+    let duplicates = a.merge(c);
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].uid(), Some("2"));
+    let uids: Vec<_> = a.components().filter_map(|c| c.uid()).collect();
+    assert_eq!(uids, ["1", "2", "1", "3"]);
+}
+
+#[test]
+fn duplicate_timezones() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_timezone(TimeZone::fixed_offset("Europe/Berlin", 60));
+    calendar.add_timezone(TimeZone::fixed_offset("europe/berlin", 120));
+    calendar.add_timezone(TimeZone::fixed_offset("Asia/Tokyo", 540));
+    let ids: Vec<_> = calendar.timezone_ids().collect();
+    assert_eq!(ids, ["Europe/Berlin", "Asia/Tokyo"]);
+
+    let mut other = ICalendar::new("2.0", "other");
+    other.add_timezone(TimeZone::fixed_offset("ASIA/TOKYO", 540));
+    calendar.append(other);
+    assert_eq!(calendar.timezone_ids().count(), 2);
+}
+
+#[test]
+fn validate_date_lists() {
+    use ics::parameters::Value;
+    use ics::properties::{ExDate, RDate};
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(ExDate::from_dates(&["19970902", "19970903"]));
+    event.push(RDate::from_dates(&["19970904T090000Z/PT1H"]));
+    assert_eq!(event.validate(), Ok(()));
+
+    let mut mixed = ExDate::new("19970902,19970903T090000Z");
+    mixed.add(Value::DATE);
+    event.push(mixed);
+    assert_eq!(
+        event.validate(),
+        Err(ValidationError::InvalidValue {
+            component: "VEVENT",
+            property: "EXDATE"
+        })
+    );
+
+    let mut todo = ToDo::new("1", "19970901T130000Z");
+    todo.push(RDate::new("19970904"));
+    assert_eq!(
+        todo.validate(),
+        Err(ValidationError::InvalidValue {
+            component: "VTODO",
+            property: "RDATE"
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn save_file_with_bom() {
+    let path = std::env::temp_dir().join("ics-bom.ics");
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "19970901T130000Z"));
+    calendar.save_file_with_bom(&path).unwrap();
+    let content = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(content.starts_with(b"\xEF\xBB\xBFBEGIN:VCALENDAR\r\n"));
+    assert_eq!(ICalendar::from_reader(&content[..]).unwrap(), calendar);
+}
+
+#[cfg(all(feature = "std", feature = "flate2"))]
+#[test]
+fn save_file_gz() {
+    use flate2::read::GzDecoder;
+
+    let path = std::env::temp_dir().join("ics-gzip.ics.gz");
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "19970901T130000Z"));
+    calendar.save_file_gz(&path).unwrap();
+    let content = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+    assert!(content.starts_with(&[0x1f, 0x8b]));
+    let calendar_gz = ICalendar::from_reader(GzDecoder::new(&content[..])).unwrap();
+    assert_eq!(calendar_gz, calendar);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn unified_error() {
+    use ics::Error;
+
+    fn save(calendar: &ICalendar, path: &std::path::Path) -> Result<ICalendar<'static>, Error> {
+        calendar.save_file_validated(path)?;
+        let calendar = ICalendar::parse(&std::fs::read_to_string(path)?)?;
+        Ok(calendar)
+    }
+
+    let path = std::env::temp_dir().join("ics-unified-error.ics");
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(Event::new("1", "19970901T130000Z"));
+    assert_eq!(save(&calendar, &path).unwrap(), calendar);
+    let _ = std::fs::remove_file(&path);
+
+    calendar.remove("VERSION");
+    match save(&calendar, &path) {
+        Err(Error::Validation(error)) => assert_eq!(
+            error,
+            ValidationError::MissingProperty {
+                component: "VCALENDAR",
+                property: "VERSION"
+            }
+        ),
+        result => panic!("expected a validation error, got {:?}", result),
+    }
+    let error = save(&calendar, &path).unwrap_err();
+    assert_eq!(error.to_string(), "invalid iCalendar object");
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(
+        source.to_string(),
+        "VCALENDAR is missing the required VERSION property"
+    );
+}
+
+#[test]
+fn method_and_calscale() {
+    use ics::properties::Method;
+
+    let expected = "BEGIN:VCALENDAR\r\n\
+                    VERSION:2.0\r\n\
+                    PRODID:ics-rs\r\n\
+                    METHOD:CANCEL\r\n\
+                    CALSCALE:GREGORIAN\r\n\
+                    BEGIN:VEVENT\r\n\
+                    UID:1\r\n\
+                    DTSTAMP:19970901T130000Z\r\n\
+                    END:VEVENT\r\n\
+                    END:VCALENDAR\r\n";
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.method(Method::request());
+    calendar.add_event(Event::new("1", "19970901T130000Z"));
+    calendar.calscale("GREGORIAN");
+    calendar.method(Method::cancel());
+    assert_eq!(calendar.to_string(), expected);
+}
+
+#[test]
+fn diff_events() {
+    use ics::PropertyChange;
+
+    let mut meeting = Event::new("1", "19970901T130000Z");
+    meeting.push(Summary::new("Meeting"));
+    meeting.push(Categories::new("WORK"));
+    let mut instance = Event::new("1", "19970901T130000Z");
+    instance.push(RecurrenceID::new("19970908T090000Z"));
+    let mut old = ICalendar::new("2.0", "ics-rs");
+    old.add_event(meeting);
+    old.add_event(instance);
+    old.add_event(Event::new("2", "19970901T130000Z"));
+    assert!(old.diff(&old.clone()).is_empty());
+
+    let mut meeting = Event::new("1", "19970901T130000Z");
+    meeting.push(Summary::new("Team meeting"));
+    meeting.push(DtStart::new("19970908T090000Z"));
+    let mut instance = Event::new("1", "19970901T130000Z");
+    instance.push(RecurrenceID::new("19970908T090000Z"));
+    let mut new = ICalendar::new("2.0", "ics-rs");
+    new.add_event(instance);
+    new.add_event(meeting);
+    new.add_event(Event::new("3", "19970901T130000Z"));
+
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.removed()
+            .map(|event| event.get("UID").unwrap().value())
+            .collect::<Vec<_>>(),
+        ["2"]
+    );
+    assert_eq!(
+        diff.added()
+            .map(|event| event.get("UID").unwrap().value())
+            .collect::<Vec<_>>(),
+        ["3"]
+    );
+    let modified: Vec<_> = diff.modified().collect();
+    assert_eq!(modified.len(), 1);
+    assert_eq!(modified[0].uid(), "1");
+    assert_eq!(modified[0].recurrence_id(), None);
+    match modified[0].changes() {
+        [PropertyChange::Modified { old, new }, PropertyChange::Removed(categories), PropertyChange::Added(dtstart)] =>
+        {
+            assert_eq!(old.value(), "Meeting");
+            assert_eq!(new.value(), "Team meeting");
+            assert_eq!(categories.key(), "CATEGORIES");
+            assert_eq!(dtstart.key(), "DTSTART");
+        }
+        changes => panic!("unexpected changes {:?}", changes),
+    }
+}
+
+#[test]
+fn diff_duplicate_instances() {
+    use ics::PropertyChange;
+
+    let summaries = |calendar: &mut ICalendar<'static>, summaries: &[&'static str]| {
+        for &summary in summaries {
+            let mut event = Event::new("1", "19970901T130000Z");
+            event.push(Summary::new(summary));
+            calendar.add_event(event);
+        }
+    };
+    let mut old = ICalendar::new("2.0", "ics-rs");
+    summaries(&mut old, &["A", "B"]);
+    let mut new = ICalendar::new("2.0", "ics-rs");
+    summaries(&mut new, &["A", "C", "D"]);
+
+    let diff = old.diff(&new);
+    let summary = |event: &Event| event.get("SUMMARY").unwrap().value().to_string();
+    assert_eq!(diff.removed().count(), 0);
+    assert_eq!(diff.added().map(summary).collect::<Vec<_>>(), ["D"]);
+    let modified: Vec<_> = diff.modified().collect();
+    assert_eq!(modified.len(), 1);
+    match modified[0].changes() {
+        [PropertyChange::Modified { old, new }] => {
+            assert_eq!((old.value(), new.value()), ("B", "C"))
+        }
+        changes => panic!("unexpected changes {:?}", changes),
+    }
+
+    let diff = new.diff(&old);
+    assert_eq!(diff.removed().map(summary).collect::<Vec<_>>(), ["D"]);
+    assert_eq!(diff.added().count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_with_fold_width() {
+    use ics::contentline::unfold;
+    use ics::WriteOptions;
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(Summary::new("A".repeat(200)));
+    event.push(Description::new(escape_text(
+        "Line one\nLine two, 老虎 ".repeat(8),
+    )));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+
+    let options = WriteOptions::default().fold_width(76);
+    let mut output = Vec::new();
+    calendar.write_with_options(&mut output, &options).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    assert!(output.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(output.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+    assert!(output.split("\r\n").all(|line| line.len() <= 76));
+    assert!(output.contains(&format!("SUMMARY:{}\r\n A", "A".repeat(68))));
+
+    let mut unfolded = String::new();
+    unfold(&mut unfolded, &output).unwrap();
+    let mut expected = String::new();
+    unfold(&mut expected, &calendar.to_string()).unwrap();
+    assert_eq!(unfolded, expected);
+    assert_eq!(ICalendar::parse(&output).unwrap(), calendar);
+}
+
+#[test]
+fn display_name_and_description() {
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.display_name("Old name");
+    calendar.display_name("Holidays, Berlin");
+    calendar.description("Public holidays; updated daily");
+    assert_eq!(
+        calendar.get("X-WR-CALNAME").unwrap().value(),
+        "Holidays\\, Berlin"
+    );
+    assert_eq!(
+        calendar.get("X-WR-CALDESC").unwrap().value(),
+        "Public holidays\\; updated daily"
+    );
+    assert_eq!(calendar.get_all("X-WR-CALNAME").count(), 1);
+
+    #[cfg(feature = "rfc7986")]
+    assert_eq!(
+        calendar.to_string(),
+        "BEGIN:VCALENDAR\r\n\
+         VERSION:2.0\r\n\
+         PRODID:ics-rs\r\n\
+         NAME:Holidays\\, Berlin\r\n\
+         X-WR-CALNAME:Holidays\\, Berlin\r\n\
+         DESCRIPTION:Public holidays\\; updated daily\r\n\
+         X-WR-CALDESC:Public holidays\\; updated daily\r\n\
+         END:VCALENDAR\r\n"
+    );
+    #[cfg(not(feature = "rfc7986"))]
+    assert!(calendar.get("NAME").is_none() && calendar.get("DESCRIPTION").is_none());
+}
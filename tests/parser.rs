@@ -0,0 +1,331 @@
+use ics::components::{Component, Property};
+use ics::parameters::{FmtType, PartStat};
+use ics::properties::{
+    Attach, Attendee, Due, Duration, Organizer, Repeat, Sequence, Status, Summary, Trigger,
+};
+use ics::{Alarm, CalendarComponent, ICalendar, ToDo};
+
+#[test]
+fn parse_todo() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:-//ABC Corporation//NONSGML My Product//EN\r\n\
+                 BEGIN:VTODO\r\n\
+                 UID:b68378cf-872d-44f1-9703-5e3725c56e71\r\n\
+                 DTSTAMP:19980130T134500Z\r\n\
+                 ORGANIZER:mailto:unclesam@example.com\r\n\
+                 ATTENDEE;PARTSTAT=ACCEPTED:mailto:jqpublic@example.com\r\n\
+                 DUE:19980415T000000\r\n\
+                 STATUS:NEEDS-ACTION\r\n\
+                 SUMMARY:Submit Income Taxes\r\n\
+                 SEQUENCE:2\r\n\
+                 BEGIN:VALARM\r\n\
+                 ACTION:AUDIO\r\n\
+                 TRIGGER:19980403T120000Z\r\n\
+                 ATTACH;FMTTYPE=audio/basic:http://example.com/pub/audio-files/ssbanner.aud\r\n\
+                 REPEAT:4\r\n\
+                 DURATION:PT1H\r\n\
+                 END:VALARM\r\n\
+                 END:VTODO\r\n\
+                 END:VCALENDAR\r\n";
+
+    let mut todo = ToDo::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19980130T134500Z");
+    todo.push(Organizer::new("mailto:unclesam@example.com"));
+    let mut attendee = Attendee::new("mailto:jqpublic@example.com");
+    attendee.add(PartStat::ACCEPTED);
+    todo.push(attendee);
+    todo.push(Due::new("19980415T000000"));
+    todo.push(Status::needs_action());
+    todo.push(Summary::new("Submit Income Taxes"));
+    todo.push(Sequence::new("2"));
+    let mut alarm = Alarm::audio(Trigger::new("19980403T120000Z"));
+    let mut attach = Attach::new("http://example.com/pub/audio-files/ssbanner.aud");
+    attach.add(FmtType::new("audio/basic"));
+    alarm.push(attach);
+    alarm.push(Repeat::new("4"));
+    alarm.push(Duration::new("PT1H"));
+    todo.add_alarm(alarm);
+
+    let mut expected = ICalendar::new("2.0", "-//ABC Corporation//NONSGML My Product//EN");
+    expected.add_todo(todo);
+
+    let calendar = ICalendar::parse(input).unwrap();
+    assert_eq!(calendar, expected);
+    assert_eq!(calendar.to_string(), input);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_folded_lines() {
+    use ics::properties::Description;
+    use ics::{escape_text, Event};
+
+    let mut event = Event::new("b68378cf-872d-44f1-9703-5e3725c56e71", "19960704T120000Z");
+    event.push(Description::new(escape_text(
+        "Networld+Interop Conference and Exhibit\n\
+         Atlanta World Congress Center\n\
+         Atlanta, Georgia, 老虎",
+    )));
+    let mut expected = ICalendar::new("2.0", "-//xyz Corp//NONSGML PDA Calendar Version 1.0//EN");
+    expected.add_event(event);
+
+    let calendar = ICalendar::from_reader(expected.to_string().as_bytes()).unwrap();
+    assert_eq!(calendar, expected);
+}
+
+#[test]
+fn parse_unknown_components() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 X-WR-CALNAME:Holidays\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:19960704T120000Z\r\n\
+                 BEGIN:X-LOCATION\r\n\
+                 X-NAME:Office\r\n\
+                 END:X-LOCATION\r\n\
+                 END:VEVENT\r\n\
+                 BEGIN:X-CUSTOM\r\n\
+                 X-PROPERTY;X-PARAMETER=Value:Text\r\n\
+                 END:X-CUSTOM\r\n\
+                 END:VCALENDAR\r\n";
+
+    let mut location = Component::new("X-LOCATION");
+    location.add_property(Property::new("X-NAME", "Office"));
+    let mut event = Component::new("VEVENT");
+    event.add_property(Property::new("UID", "1"));
+    event.add_property(Property::new("DTSTAMP", "19960704T120000Z"));
+    event.add_component(location);
+    let mut custom = Component::new("X-CUSTOM");
+    let mut property = Property::new("X-PROPERTY", "Text");
+    property.append(ics::parameters!("X-PARAMETER" => "Value"));
+    custom.add_property(property);
+
+    let mut expected = ICalendar::new("2.0", "ics-rs");
+    expected.push(Property::new("X-WR-CALNAME", "Holidays"));
+    expected.add_component(event);
+    expected.add_component(custom);
+
+    let calendar = ICalendar::parse(input).unwrap();
+    assert_eq!(calendar, expected);
+    assert_eq!(calendar.to_string(), input);
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(ICalendar::parse("").unwrap_err().line(), 0);
+    assert_eq!(ICalendar::parse("VERSION:2.0\r\n").unwrap_err().line(), 1);
+    let input = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n";
+    let error = ICalendar::parse(input).unwrap_err();
+    assert_eq!(error.to_string(), "line 2: missing `END:VCALENDAR`");
+    let input = "BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\nBEGIN:VCALENDAR\r\n";
+    assert_eq!(ICalendar::parse(input).unwrap_err().line(), 3);
+}
+
+#[test]
+fn parse_availability() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VAVAILABILITY\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:20111005T133225Z\r\n\
+                 BEGIN:AVAILABLE\r\n\
+                 UID:2\r\n\
+                 DTSTAMP:20111005T133225Z\r\n\
+                 DTSTART:20111003T090000Z\r\n\
+                 END:AVAILABLE\r\n\
+                 END:VAVAILABILITY\r\n\
+                 END:VCALENDAR\r\n";
+
+    let calendar = ICalendar::parse(input).unwrap();
+    match calendar.components().next() {
+        Some(CalendarComponent::Availability(availability)) => {
+            assert_eq!(availability.validate(), Ok(()))
+        }
+        _ => panic!("expected an availability"),
+    };
+    assert_eq!(calendar.to_string(), input);
+}
+
+#[test]
+fn parse_participant() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:20221005T133225Z\r\n\
+                 BEGIN:VALARM\r\n\
+                 ACTION:AUDIO\r\n\
+                 TRIGGER:-PT15M\r\n\
+                 END:VALARM\r\n\
+                 BEGIN:VPARTICIPANT\r\n\
+                 UID:2\r\n\
+                 PARTICIPANT-TYPE:SPEAKER\r\n\
+                 CALENDAR-ADDRESS:mailto:jane@example.com\r\n\
+                 BEGIN:VLOCATION\r\n\
+                 UID:3\r\n\
+                 LOCATION-TYPE:office\r\n\
+                 END:VLOCATION\r\n\
+                 END:VPARTICIPANT\r\n\
+                 END:VEVENT\r\n\
+                 END:VCALENDAR\r\n";
+
+    let calendar = ICalendar::parse(input).unwrap();
+    match calendar.components().next() {
+        Some(CalendarComponent::Event(event)) => assert_eq!(event.validate(), Ok(())),
+        _ => panic!("expected an event"),
+    };
+    assert_eq!(calendar.to_string(), input);
+}
+
+#[test]
+fn parse_property_line() {
+    let mut expected = Property::new("ATTENDEE", "mailto:jsmith@example.com");
+    expected.append(
+        ics::parameters!("DIR" => "\"ldap://example.com:6666/o=ABC\""; "PARTSTAT" => "ACCEPTED"),
+    );
+    let line = "ATTENDEE;DIR=\"ldap://example.com:6666/o=ABC\";PARTSTAT=ACCEPTED:mailto:jsmit\r\n h@example.com\r\n";
+    let property = Property::parse_line(line).unwrap();
+    assert_eq!(property, expected);
+    assert_eq!(property.to_string(), line);
+
+    assert_eq!(Property::parse_line("").unwrap_err().line(), 0);
+    let error = Property::parse_line("SUMMARY:A\r\nSUMMARY:B\r\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 2: expected a single content line");
+}
+
+#[test]
+fn property_parameters() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:19970901T130000Z\r\n\
+                 DTSTART;VALUE=DATE;X-SOURCE=\"import\":19970903\r\n\
+                 END:VEVENT\r\n\
+                 END:VCALENDAR\r\n";
+
+    let calendar = ICalendar::parse(input).unwrap();
+    let event = calendar.events().next().unwrap();
+    let dtstart = event.get("DTSTART").unwrap();
+    assert_eq!(dtstart.value(), "19970903");
+    let parameters: Vec<_> = dtstart.parameters().collect();
+    assert_eq!(parameters, [("VALUE", "DATE"), ("X-SOURCE", "\"import\"")]);
+    assert_eq!(event.get("UID").unwrap().parameters().next(), None);
+}
+
+#[test]
+fn parse_poll() {
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VPOLL\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:20150403T142000Z\r\n\
+                 POLL-MODE:BASIC\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:20150403T142000Z\r\n\
+                 POLL-ITEM-ID:1\r\n\
+                 END:VEVENT\r\n\
+                 END:VPOLL\r\n\
+                 BEGIN:VPOLL\r\n\
+                 UID:2\r\n\
+                 DTSTAMP:20150403T142000Z\r\n\
+                 BEGIN:VVOTER\r\n\
+                 CALENDAR-ADDRESS:mailto:jane@example.com\r\n\
+                 END:VVOTER\r\n\
+                 END:VPOLL\r\n\
+                 END:VCALENDAR\r\n";
+
+    let calendar = ICalendar::parse(input).unwrap();
+    let mut components = calendar.components();
+    match components.next() {
+        Some(CalendarComponent::Poll(poll)) => {
+            assert_eq!(poll.get("POLL-MODE").unwrap().value(), "BASIC");
+            assert_eq!(poll.candidates().count(), 1);
+        }
+        _ => panic!("expected a poll"),
+    };
+    match components.next() {
+        Some(CalendarComponent::Other(component)) => {
+            assert!(component.to_string().contains("\r\nBEGIN:VVOTER\r\n"))
+        }
+        _ => panic!("expected a generic component"),
+    };
+    assert_eq!(calendar.to_string(), input);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_with_handler() {
+    use ics::components::Property;
+    use ics::ComponentHandler;
+
+    #[derive(Default)]
+    struct Handler {
+        properties: Vec<String>,
+        events: Vec<String>,
+        other: usize,
+    }
+
+    impl ComponentHandler for Handler {
+        fn component(&mut self, component: CalendarComponent<'static>) {
+            match component {
+                CalendarComponent::Event(event) => {
+                    assert!(event.to_string().contains("\r\nBEGIN:VALARM\r\n"));
+                    self.events
+                        .push(event.get("UID").unwrap().value().to_owned())
+                }
+                _ => self.other += 1,
+            }
+        }
+
+        fn property(&mut self, property: Property<'static>) {
+            self.properties.push(property.to_string());
+        }
+    }
+
+    let input = "BEGIN:VCALENDAR\r\n\
+                 VERSION:2.0\r\n\
+                 PRODID:ics-rs\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:1\r\n\
+                 DTSTAMP:19960704T120000Z\r\n\
+                 BEGIN:VALARM\r\n\
+                 ACTION:DISPLAY\r\n\
+                 TRIGGER:-PT15M\r\n\
+                 DESCRIPTION:Reminder\r\n\
+                 END:VALARM\r\n\
+                 END:VEVENT\r\n\
+                 BEGIN:VTODO\r\n\
+                 UID:2\r\n\
+                 DTSTAMP:19960704T120000Z\r\n\
+                 END:VTODO\r\n\
+                 BEGIN:VEVENT\r\n\
+                 UID:3\r\n\
+                 DTSTAMP:19960704T120000Z\r\n\
+                 BEGIN:VALARM\r\n\
+                 ACTION:AUDIO\r\n\
+                 TRIGGER:-PT5M\r\n\
+                 END:VALARM\r\n\
+                 END:VEVENT\r\n\
+                 END:VCALENDAR\r\n";
+
+    let mut handler = Handler::default();
+    ics::parse_with_handler(input.as_bytes(), &mut handler).unwrap();
+    assert_eq!(handler.properties, ["VERSION:2.0\r\n", "PRODID:ics-rs\r\n"]);
+    assert_eq!(handler.events, ["1", "3"]);
+    assert_eq!(handler.other, 1);
+
+    // Components before an error are still handled
+    let mut count = 0;
+    let error = ics::parse_with_handler(&input.as_bytes()[..input.len() - 15], &mut |_| count += 1)
+        .unwrap_err();
+    assert_eq!(error.to_string(), "line 24: missing `END:VCALENDAR`");
+    assert_eq!(count, 3);
+}
@@ -0,0 +1,561 @@
+use ics::properties::{Frequency, RRule, RRuleBuilder, RRuleError, Weekday};
+
+#[test]
+fn rrule() {
+    let rrule = RRuleBuilder::new()
+        .freq(Frequency::Monthly)
+        .interval(2)
+        .until("19971224T000000Z")
+        .by_month_day(&[1, -1])
+        .build();
+    assert_eq!(
+        rrule,
+        Ok(RRule::new(
+            "FREQ=MONTHLY;UNTIL=19971224T000000Z;INTERVAL=2;BYMONTHDAY=1,-1"
+        ))
+    );
+}
+
+#[test]
+fn rrule_errors() {
+    let rrule = RRuleBuilder::new().by_day(&[Weekday::Sunday]).build();
+    assert_eq!(rrule, Err(RRuleError::MissingFrequency));
+
+    let rrule = RRuleBuilder::new()
+        .freq(Frequency::Daily)
+        .count(10)
+        .until("19971224T000000Z")
+        .build();
+    assert_eq!(rrule, Err(RRuleError::CountAndUntil));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_date_times() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use ics::parameters::Value;
+    use ics::properties::{DtStamp, DtStart};
+
+    let datetime = Utc.with_ymd_and_hms(1996, 9, 18, 14, 30, 0).unwrap();
+    assert_eq!(
+        DtStamp::from_datetime(datetime),
+        DtStamp::new("19960918T143000Z")
+    );
+
+    let date = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let mut expected = DtStart::new("20240101");
+    expected.add(Value::DATE);
+    assert_eq!(DtStart::from_date(date), expected);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_date_times() {
+    use ics::parameters::Value;
+    use ics::properties::{DtStart, Due};
+    use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+    // 13:00 in New York is 17:00 UTC (RFC5545 3.8.2.4. Date-Time Start)
+    let date = Date::from_calendar_date(1997, Month::July, 14).unwrap();
+    let local = PrimitiveDateTime::new(date, Time::from_hms(13, 0, 0).unwrap())
+        .assume_offset(UtcOffset::from_hms(-4, 0, 0).unwrap());
+    assert_eq!(
+        DtStart::from_offset_datetime(local),
+        DtStart::new("19970714T170000Z")
+    );
+
+    let date = Date::from_calendar_date(1998, Month::April, 15).unwrap();
+    let mut expected = Due::new("19980415");
+    expected.add(Value::DATE);
+    assert_eq!(Due::from_date(date), expected);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn random_uid() {
+    use ics::components::Property;
+    use ics::properties::UID;
+
+    let uid = Property::from(UID::random());
+    assert_eq!(uid.value().len(), 36);
+    assert_ne!(Property::from(UID::random()), uid);
+    let uid = Property::from(UID::random_with_domain("example.com"));
+    assert!(uid.value().ends_with("@example.com"));
+}
+
+#[test]
+fn attendee_builder() {
+    use ics::parameters::{PartStat, Role};
+    use ics::properties::{Attendee, AttendeeBuilder};
+
+    let attendee = AttendeeBuilder::new("mailto:jsmith@example.com")
+        .common_name("John Smith")
+        .role(Role::CHAIR)
+        .part_stat(PartStat::DELEGATED)
+        .rsvp(false)
+        .delegated_to("mailto:jdoe@example.com")
+        .delegated_to("mailto:jqpublic@example.com")
+        .build();
+    let mut expected = Attendee::new("mailto:jsmith@example.com");
+    expected.append(ics::parameters!(
+        "CN" => "John Smith";
+        "ROLE" => "CHAIR";
+        "PARTSTAT" => "DELEGATED";
+        "RSVP" => "FALSE";
+        "DELEGATED-TO" => "\"mailto:jdoe@example.com\",\"mailto:jqpublic@example.com\""
+    ));
+    assert_eq!(attendee, expected);
+
+    let attendee = AttendeeBuilder::new("mailto:jsmith@example.com")
+        .rsvp(true)
+        .build();
+    let mut expected = Attendee::new("mailto:jsmith@example.com");
+    expected.add(ics::parameters::RSVP::True);
+    assert_eq!(attendee, expected);
+}
+
+#[test]
+fn organizer_builder() {
+    use ics::components::Property;
+    use ics::parameters::Dir;
+    use ics::properties::{Organizer, OrganizerBuilder};
+
+    let organizer = OrganizerBuilder::new("mailto:john@example.com")
+        .common_name("John")
+        .sent_by("mailto:jane@example.com")
+        .build();
+    assert_eq!(
+        Property::from(organizer).to_string(),
+        "ORGANIZER;CN=John;SENT-BY=\"mailto:jane@example.com\":mailto:john@example.com\r\n"
+    );
+
+    let organizer = OrganizerBuilder::new("mailto:john@example.com")
+        .dir("ldap://example.com:6666/o=ABC%20Industries,c=US")
+        .build();
+    let mut expected = Organizer::new("mailto:john@example.com");
+    expected.add(Dir::new("ldap://example.com:6666/o=ABC%20Industries,c=US"));
+    assert_eq!(organizer, expected);
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn color() {
+    use ics::properties::Color;
+    use ics::{Event, ICalendar};
+
+    assert_eq!(
+        Color::from_css_name("DarkSlateGray"),
+        Color::new("darkslategray")
+    );
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(Color::from_css_name("turquoise"));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.push(Color::from_css_name("navy"));
+    calendar.add_event(event);
+    let output = calendar.to_string();
+    assert!(output.contains("PRODID:ics-rs\r\nCOLOR:navy\r\n"));
+    assert!(output.contains("COLOR:turquoise\r\n"));
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn image() {
+    use ics::components::Property;
+    use ics::properties::Image;
+
+    let image = Property::from(Image::uri("https://example.com/images/party.png"));
+    assert_eq!(
+        image.to_string(),
+        "IMAGE;VALUE=URI:https://example.com/images/party.png\r\n"
+    );
+
+    let data: Vec<u8> = (0..=255).collect();
+    let image = Property::from(Image::binary(&data, "image/png")).to_string();
+    assert!(
+        image.starts_with("IMAGE;ENCODING=BASE64;FMTTYPE=image/png;VALUE=BINARY:AAECAwQFBgcICQoL")
+    );
+    assert!(image.ends_with("+/w==\r\n"));
+    assert!(image.split("\r\n").all(|line| line.len() <= 75));
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn conference() {
+    use ics::components::Property;
+    use ics::parameters::Feature;
+    use ics::properties::Conference;
+
+    let conference = Conference::uri("https://chat.example.com/audio?id=123456")
+        .feature(&[Feature::AUDIO, Feature::VIDEO])
+        .label("Attendee dial-in");
+    assert_eq!(
+        Property::from(conference).to_string(),
+        "CONFERENCE;FEATURE=AUDIO,VIDEO;LABEL=Attendee dial-in;VALUE=URI:https://cha\r\n t.example.com/audio?id=123456\r\n"
+    );
+}
+
+#[cfg(feature = "rfc7986")]
+#[test]
+fn email() {
+    use ics::prelude::*;
+
+    let mut alarm = Alarm::email(
+        Trigger::new("-PT30M"),
+        Description::new("Meeting starts soon"),
+        Summary::new("Reminder"),
+    );
+    alarm.push(Attendee::new("mailto:jane@example.com"));
+    alarm.push(Email::new("jane@example.com"));
+    assert_eq!(alarm.validate(), Ok(()));
+    assert!(alarm.get("EMAIL").is_some());
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.push(Email::new("calendar@example.com"));
+    assert!(calendar
+        .to_string()
+        .contains("PRODID:ics-rs\r\nEMAIL:calendar@example.com\r\n"));
+}
+
+#[cfg(all(feature = "rfc7986", debug_assertions))]
+#[test]
+#[should_panic(expected = "email address must contain an `@`")]
+fn invalid_email() {
+    ics::properties::Email::new("example.com");
+}
+
+#[test]
+fn categories() {
+    use ics::properties::{Categories, FreeBusyTime, Resources};
+    use std::iter::FromIterator;
+
+    let categories = Categories::from_iter(vec!["APPOINTMENT", "EDUCATION, RESEARCH"]);
+    assert_eq!(
+        categories,
+        Categories::new("APPOINTMENT,EDUCATION\\, RESEARCH")
+    );
+    let resources: Resources = vec!["EASEL".to_owned(), "PROJECTOR".to_owned()]
+        .into_iter()
+        .collect();
+    assert_eq!(resources, Resources::new("EASEL,PROJECTOR"));
+    assert_eq!(
+        Categories::from_iter(Vec::<&str>::new()),
+        Categories::new("")
+    );
+
+    let periods = [
+        "19970308T160000Z/PT8H30M",
+        "19970308T230000Z/19970309T000000Z",
+    ];
+    assert_eq!(
+        FreeBusyTime::from_iter(periods.iter().cloned()),
+        FreeBusyTime::new("19970308T160000Z/PT8H30M,19970308T230000Z/19970309T000000Z")
+    );
+}
+
+#[test]
+fn date_lists() {
+    use ics::parameters::Value;
+    use ics::properties::{ExDate, RDate};
+
+    let exdate = ExDate::from_dates(&["19960402T010000Z", "19960403T010000Z"]);
+    assert_eq!(exdate, ExDate::new("19960402T010000Z,19960403T010000Z"));
+
+    let mut expected = RDate::new("19960403T020000Z/19960403T040000Z,19960404T010000Z/PT3H");
+    expected.add(Value::PERIOD);
+    let rdate = RDate::from_dates(&["19960403T020000Z/19960403T040000Z", "19960404T010000Z/PT3H"]);
+    assert_eq!(rdate, expected);
+}
+
+#[test]
+fn calendar_extensions() {
+    use ics::properties::{XWrCalName, XWrTimeZone};
+    use ics::ICalendar;
+
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.push(XWrCalName::new("Holidays"));
+    calendar.push(XWrTimeZone::new("Europe/Berlin"));
+    assert_eq!(calendar.get("X-WR-CALNAME").unwrap().value(), "Holidays");
+    assert_eq!(
+        calendar.get("X-WR-TIMEZONE").unwrap().value(),
+        "Europe/Berlin"
+    );
+}
+
+#[test]
+fn all_day_dates() {
+    use ics::properties::{DtEnd, DtStart};
+    use ics::Event;
+
+    let mut event = Event::new("1", "20240101T090000Z");
+    event.push(DtStart::date("20240101"));
+    event.push(DtEnd::date("20240102"));
+    let event = event.to_string();
+    assert!(event.contains("DTSTART;VALUE=DATE:20240101\r\n"));
+    assert!(event.contains("DTEND;VALUE=DATE:20240102\r\n"));
+}
+
+#[test]
+fn binary_attachment() {
+    use ics::properties::Attach;
+    use ics::{Event, ICalendar};
+
+    let data: Vec<u8> = (0..4096).map(|i| (i % 256) as u8).collect();
+    let attach = Attach::binary(&data, "application/octet-stream");
+    let mut event = Event::new("1", "20240101T090000Z");
+    event.push(attach.clone());
+    event.push(Attach::uri("https://example.com/agenda.pdf"));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+
+    let output = calendar.to_string();
+    assert!(output
+        .contains("ATTACH;ENCODING=BASE64;FMTTYPE=application/octet-stream;VALUE=BINARY:AAECAw"));
+    assert!(output.split("\r\n").all(|line| line.len() <= 75));
+    assert_eq!(ICalendar::parse(&output).unwrap(), calendar);
+}
+
+#[test]
+fn structured_data() {
+    use ics::components::Property;
+    use ics::properties::StructuredData;
+
+    let data = StructuredData::json(r#"{"seats":[1,2]}"#);
+    assert_eq!(
+        Property::from(data).to_string(),
+        "STRUCTURED-DATA;FMTTYPE=application/json:{\"seats\":[1\\,2]}\r\n"
+    );
+    let data = StructuredData::binary(b"<a/>", "application/xml");
+    assert_eq!(
+        Property::from(data).to_string(),
+        "STRUCTURED-DATA;ENCODING=BASE64;FMTTYPE=application/xml;VALUE=BINARY:PGEvPg\r\n ==\r\n"
+    );
+}
+
+#[test]
+fn request_status() {
+    use ics::properties::RequestStatus;
+
+    let status = RequestStatus::from_parts("2.0", "Success", None);
+    assert_eq!(status, RequestStatus::new("2.0;Success"));
+    let status =
+        RequestStatus::from_parts("3.1", "Invalid property value", Some("DTSTART:96-Apr-01"));
+    assert_eq!(
+        status,
+        RequestStatus::new("3.1;Invalid property value;DTSTART:96-Apr-01")
+    );
+    let status = RequestStatus::from_parts("2.8", "Success; repeated event ignored", Some("RRULE"));
+    assert_eq!(
+        status,
+        RequestStatus::new("2.8;Success\\; repeated event ignored;RRULE")
+    );
+}
+
+#[test]
+fn freebusy_periods() {
+    use ics::components::Property;
+    use ics::parameters::FBType;
+    use ics::properties::FreeBusyTime;
+    use ics::values::{Date, DateTime, Duration, Period};
+
+    let day = Date::new(1997, 3, 8);
+    let periods = [
+        Period::start_duration(
+            DateTime::new(day.clone(), 16, 0, 0),
+            Duration::new().hours(3),
+        ),
+        Period::start_end(
+            DateTime::new(day.clone(), 20, 0, 0),
+            DateTime::new(day, 21, 0, 0),
+        ),
+    ];
+    let freebusy = FreeBusyTime::from_periods(&periods, FBType::BUSY_TENTATIVE);
+    assert_eq!(
+        Property::from(freebusy).to_string(),
+        "FREEBUSY;FBTYPE=BUSY-TENTATIVE:19970308T160000Z/PT3H,19970308T200000Z/19970\r\n 308T210000Z\r\n"
+    );
+}
+
+#[test]
+fn line_breaks() {
+    use ics::components::Property;
+    use ics::properties::Description;
+
+    let description = Property::from(Description::new("One\nTwo"));
+    assert_eq!(description.to_string(), "DESCRIPTION:One\\nTwo\r\n");
+}
+
+#[test]
+fn numbers() {
+    use ics::properties::{Priority, Sequence};
+
+    assert_eq!(Priority::from_level(1), Priority::new("1"));
+    assert_eq!(Priority::from_level(0), Priority::default());
+    assert_eq!(Sequence::from_number(12), Sequence::new("12"));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "priority level must be between 0 and 9")]
+fn invalid_priority() {
+    ics::properties::Priority::from_level(15);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "parameter value must not contain a double quote")]
+fn invalid_parameter_value() {
+    ics::parameters::CN::new("John \"Johnny\" Doe");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "parameter value must not contain a double quote")]
+fn invalid_delegated_to() {
+    let _: ics::parameters::DelegatedTo = vec!["mailto:\"b\"@example.com"].into_iter().collect();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn created_and_last_modified_now() {
+    use ics::components::Property;
+    use ics::properties::{Created, LastModified};
+
+    let is_utc = |value: &str| {
+        value.len() == 16
+            && value.ends_with('Z')
+            && value.as_bytes()[8] == b'T'
+            && value[..8]
+                .bytes()
+                .chain(value[9..15].bytes())
+                .all(|b| b.is_ascii_digit())
+    };
+    let created = Property::from(Created::now());
+    assert_eq!(created.key(), "CREATED");
+    assert!(is_utc(created.value()));
+    let last_modified = Property::from(LastModified::now());
+    assert_eq!(last_modified.key(), "LAST-MODIFIED");
+    assert!(is_utc(last_modified.value()));
+    assert!(created.value() <= last_modified.value());
+}
+
+#[test]
+fn trigger() {
+    use ics::components::Property;
+    use ics::parameters::Related;
+    use ics::properties::Trigger;
+    use ics::values::{Date, DateTime, Duration};
+
+    let relative = Trigger::relative(Duration::new().minutes(5).negative(), Related::End);
+    assert_eq!(
+        Property::from(relative).to_string(),
+        "TRIGGER;RELATED=END:-PT5M\r\n"
+    );
+    let relative = Trigger::relative(Duration::new().hours(1), Related::Start);
+    assert_eq!(
+        Property::from(relative).to_string(),
+        "TRIGGER;RELATED=START:PT1H\r\n"
+    );
+
+    let absolute = Trigger::absolute(DateTime::new(Date::new(1997, 3, 17), 13, 30, 0));
+    assert_eq!(
+        Property::from(absolute).to_string(),
+        "TRIGGER;VALUE=DATE-TIME:19970317T133000Z\r\n"
+    );
+}
+
+#[test]
+fn contact() {
+    use ics::components::Property;
+    use ics::properties::Contact;
+
+    let contact = Contact::text("Jim Dolittle, ABC Industries, +1-919-555-1234")
+        .alt_rep("ldap://example.com:6666/o=ABC%20Industries,c=US???(cn=Jim%20Dolittle)");
+    assert_eq!(
+        Property::from(contact).to_string(),
+        "CONTACT;ALTREP=\"ldap://example.com:6666/o=ABC%20Industries,c=US???(cn=Jim%2\r\n 0Dolittle)\":Jim Dolittle\\, ABC Industries\\, +1-919-555-1234\r\n"
+    );
+}
+
+#[test]
+fn related_to() {
+    use ics::components::Property;
+    use ics::parameters::RelType;
+    use ics::properties::RelatedTo;
+
+    let related_to = Property::from(RelatedTo::new("1").rel_type(RelType::SIBLING));
+    assert_eq!(related_to.to_string(), "RELATED-TO;RELTYPE=SIBLING:1\r\n");
+    let related_to = Property::from(RelatedTo::new("1"));
+    assert_eq!(related_to.to_string(), "RELATED-TO:1\r\n");
+}
+
+#[test]
+fn delegation_lists() {
+    use ics::components::Property;
+    use ics::parameters::{DelegatedTo, Member};
+    use ics::properties::Attendee;
+
+    let mut attendee = Attendee::new("mailto:jsmith@example.com");
+    let delegated_to: DelegatedTo = vec!["mailto:a@x.com", "mailto:b@x.com"]
+        .into_iter()
+        .collect();
+    attendee.add(delegated_to);
+    let member: Member = vec!["mailto:projectA@example.com"].into_iter().collect();
+    attendee.add(member);
+    assert_eq!(
+        Property::from(attendee).to_string(),
+        "ATTENDEE;DELEGATED-TO=\"mailto:a@x.com\",\"mailto:b@x.com\";MEMBER=\"mailto:proj\r\n ectA@example.com\":mailto:jsmith@example.com\r\n"
+    );
+}
+
+#[test]
+fn text_properties() {
+    use ics::components::Property;
+    use ics::properties::{Comment, Description, Summary};
+    use ics::Event;
+
+    assert_eq!(
+        Summary::text("Lunch, then coffee"),
+        Summary::new("Lunch\\, then coffee")
+    );
+    assert_eq!(Description::text("a;b\nc"), Description::new("a\\;b\\nc"));
+
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(Comment::text("First, a comment"));
+    event.push(Comment::text("Second; another comment"));
+    let comments: Vec<_> = event.get_all("COMMENT").map(Property::value).collect();
+    assert_eq!(
+        comments,
+        ["First\\, a comment", "Second\\; another comment"]
+    );
+}
+
+#[test]
+fn recurrence_id_range() {
+    use ics::components::Property;
+    use ics::parameters::Range;
+    use ics::properties::RecurrenceID;
+
+    let recurrence_id = RecurrenceID::date("19960120").range(Range::ThisAndFuture);
+    assert_eq!(
+        Property::from(recurrence_id).to_string(),
+        "RECURRENCE-ID;RANGE=THISANDFUTURE;VALUE=DATE:19960120\r\n"
+    );
+}
+
+#[test]
+fn url_parse() {
+    use ics::components::Property;
+    use ics::properties::{URLError, URL};
+
+    let url = URL::parse("http://example.com/search?q=a,b;c").unwrap();
+    assert_eq!(
+        Property::from(url).to_string(),
+        "URL:http://example.com/search?q=a,b;c\r\n"
+    );
+    assert!(URL::parse("cid:jsmith.part3.960817T083000.xyzMail@example.com").is_ok());
+    assert!(URL::parse("x-custom+scheme.v1:resource").is_ok());
+    assert_eq!(URL::parse("example.com"), Err(URLError));
+    assert_eq!(URL::parse(":no-scheme"), Err(URLError));
+    assert_eq!(URL::parse("1http://example.com"), Err(URLError));
+    assert_eq!(URL::parse("http\\://example.com"), Err(URLError));
+}
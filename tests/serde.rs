@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+use ics::components::Component;
+use ics::parameters::PartStat;
+use ics::properties::{Attendee, Summary, Trigger};
+use ics::{Alarm, Event, ICalendar};
+
+#[test]
+fn serialize_calendar() {
+    let mut attendee = Attendee::new("mailto:jsmith@example.com");
+    attendee.add(PartStat::ACCEPTED);
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.push(attendee);
+    event.add_alarm(Alarm::audio(Trigger::new("-PT15M")));
+    let mut calendar = ICalendar::new("2.0", "ics-rs");
+    calendar.add_event(event);
+    calendar.add_component(Component::new("X-CUSTOM"));
+
+    let json = serde_json::to_value(&calendar).unwrap();
+    assert_eq!(json["name"], "VCALENDAR");
+    assert_eq!(json["properties"][0]["key"], "VERSION");
+    let event = &json["subcomponents"][0];
+    assert_eq!(event["name"], "VEVENT");
+    assert_eq!(event["properties"][2]["parameters"]["PARTSTAT"], "ACCEPTED");
+    assert_eq!(event["subcomponents"][0]["name"], "VALARM");
+    assert_eq!(json["subcomponents"][1]["name"], "X-CUSTOM");
+
+    let deserialized: ICalendar = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, calendar);
+}
+
+#[test]
+fn deserialize_wrong_name() {
+    let summary = serde_json::to_string(&Summary::new("Meeting")).unwrap();
+    assert!(serde_json::from_str::<Summary>(&summary).is_ok());
+    assert!(serde_json::from_str::<Attendee>(&summary).is_err());
+    let event = serde_json::to_string(&Event::new("1", "19970901T130000Z")).unwrap();
+    assert!(serde_json::from_str::<ICalendar>(&event).is_err());
+}
+
+#[test]
+fn serialize_participant() {
+    use ics::properties::ParticipantType;
+    use ics::{Participant, StructuredLocation};
+
+    let mut participant = Participant::new("2", ParticipantType::speaker());
+    participant.add_location(StructuredLocation::new("3"));
+    let mut event = Event::new("1", "19970901T130000Z");
+    event.add_alarm(Alarm::audio(Trigger::new("-PT15M")));
+    event.add_participant(participant);
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert_eq!(json["subcomponents"][0]["name"], "VALARM");
+    assert_eq!(json["subcomponents"][1]["name"], "VPARTICIPANT");
+    assert_eq!(
+        json["subcomponents"][1]["subcomponents"][0]["name"],
+        "VLOCATION"
+    );
+
+    let deserialized: Event = serde_json::from_value(json).unwrap();
+    assert_eq!(deserialized, event);
+}